@@ -0,0 +1,62 @@
+//! Times the generated `f32` and `f64` operators, run with `cargo bench`.
+//! The sum is compared with the idiomatic vectorized loop,
+//! and the dot product with a sequential one which cannot be vectorized.
+//! Their assembly is checked by `tests/vectorization.rs`.
+use std::hint::black_box;
+use std::ops::*;
+use std::time::{Duration, Instant};
+
+use simp_linalg_proc_macro::*;
+
+pub struct Vector<T> {
+    list: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(list: Vec<T>) -> Self {
+        Vector { list }
+    }
+}
+
+vector_ops_impl!(Vector<T>: add, dot);
+
+const LENGTH: usize = 1 << 14;
+const ROUNDS: u32 = 2000;
+
+/// The mean time of one run of `f`.
+fn time<R>(mut f: impl FnMut() -> R) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(f());
+    }
+    start.elapsed() / ROUNDS
+}
+
+macro_rules! bench_float {
+    ($float:ty) => {{
+        let a = Vector::from((0..LENGTH).map(|idx| idx as $float).collect::<Vec<$float>>());
+        let b = Vector::from((0..LENGTH).map(|idx| (LENGTH - idx) as $float).collect::<Vec<$float>>());
+
+        let add = time(|| black_box(&a) + black_box(&b));
+        // The idiomatic loop, which the compiler vectorizes.
+        let add_reference = time(|| {
+            black_box(&a).list.iter().zip(&b.list).map(|(a, b)| a + b).collect::<Vec<$float>>()
+        });
+
+        let dot = time(|| black_box(&a) * black_box(&b));
+        // A single accumulator, whose additions cannot be reordered.
+        let dot_reference = time(|| {
+            black_box(&a).list.iter().zip(&b.list).fold(0.0, |sum: $float, (a, b)| sum + a * b)
+        });
+
+        println!(
+            "{}: add {:?} (iterator loop {:?}), dot {:?} (sequential {:?})",
+            stringify!($float), add, add_reference, dot, dot_reference
+        );
+    }};
+}
+
+fn main() {
+    bench_float!(f32);
+    bench_float!(f64);
+}
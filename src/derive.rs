@@ -0,0 +1,258 @@
+use quote::{quote, ToTokens};
+use syn::{
+    Data, DeriveInput, Fields, GenericArgument, Generics, Index, Lit, Meta, NestedMeta,
+    PathArguments, Type, parse_quote
+};
+
+use crate::{VectorTarget, combine_errors, left_scalar_mul_impl, parallel, scalar_vector_mul_error};
+use crate::ops::{SUPPORTED_OPS, operator_impls};
+
+/// The contents of the `#[vector_ops(..)]` attributes.
+struct VectorOpsAttr {
+    field : Option<Lit>,
+    ops : Vec<syn::Ident>,
+    /// The scalar types of `scalars(..)`, multiplied on the left of the vector.
+    scalars : Vec<syn::Path>,
+    /// The length from which the operators run in parallel, given as `parallel_threshold = ..`.
+    parallel_threshold : Option<usize>,
+}
+
+fn parse_attrs(input : &DeriveInput) -> syn::Result<VectorOpsAttr> {
+    let mut attr = VectorOpsAttr {
+        field: None,
+        ops: Vec::new(),
+        scalars: Vec::new(),
+        parallel_threshold: None,
+    };
+
+    for meta in input.attrs.iter().filter(|attr| attr.path.is_ident("vector_ops")) {
+        let list = match meta.parse_meta()? {
+            Meta::List(list) => list,
+            other => return Err(syn::Error::new_spanned(
+                other,
+                "expected `#[vector_ops(field = \"..\", ops(..))]`"
+            )),
+        };
+
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(name_value)) if name_value.path.is_ident("field") => {
+                    attr.field = Some(name_value.lit);
+                },
+                NestedMeta::Meta(Meta::NameValue(name_value)) if name_value.path.is_ident("parallel_threshold") => {
+                    match name_value.lit {
+                        Lit::Int(threshold) => attr.parallel_threshold = Some(threshold.base10_parse()?),
+                        other => return Err(syn::Error::new_spanned(
+                            other,
+                            "expected the length as an integer"
+                        )),
+                    }
+                },
+                NestedMeta::Meta(Meta::List(ops)) if ops.path.is_ident("ops") => {
+                    for op in ops.nested {
+                        match op {
+                            NestedMeta::Meta(Meta::Path(path)) if path.get_ident().is_some() => {
+                                attr.ops.push(path.get_ident().unwrap().clone());
+                            },
+                            other => return Err(syn::Error::new_spanned(
+                                other,
+                                format!("expected one of: {}", SUPPORTED_OPS)
+                            )),
+                        }
+                    }
+                },
+                NestedMeta::Meta(Meta::List(scalars)) if scalars.path.is_ident("scalars") => {
+                    for scalar in scalars.nested {
+                        match scalar {
+                            NestedMeta::Meta(Meta::Path(path)) => attr.scalars.push(path),
+                            other => return Err(syn::Error::new_spanned(
+                                other,
+                                "expected a scalar type, such as `f32`"
+                            )),
+                        }
+                    }
+                },
+                other => return Err(syn::Error::new_spanned(
+                    other,
+                    "expected `field = \"..\"`, `ops(..)`, `scalars(..)` or `parallel_threshold = ..`"
+                )),
+            }
+        }
+    }
+
+    if attr.ops.is_empty() && attr.scalars.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            format!(
+                "missing `#[vector_ops(ops(..))]`, expected any of: {}",
+                SUPPORTED_OPS
+            )
+        ));
+    }
+
+    Ok(attr)
+}
+
+/// The element type of a `Vec<T>` or slice-like field type,
+/// such as `Box<[T]>`.
+fn element_type(ty : &Type) -> Option<&Type> {
+    let segment = match ty {
+        Type::Path(path) => path.path.segments.last()?,
+        _ => return None
+    };
+
+    let args = match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args,
+        _ => return None
+    };
+
+    match args.args.first()? {
+        GenericArgument::Type(Type::Slice(slice)) => Some(&slice.elem),
+        GenericArgument::Type(elem) => Some(elem),
+        _ => None
+    }
+}
+
+/// Whether the field type is a `PhantomData` marker.
+fn is_phantom_data(ty : &Type) -> bool {
+    match ty {
+        Type::Path(path) => path.path.segments.last()
+            .is_some_and(|segment| segment.ident == "PhantomData"),
+        _ => false
+    }
+}
+
+fn vector_target(input : &DeriveInput, field_name : Option<&Lit>) -> syn::Result<VectorTarget> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => return Err(syn::Error::new_spanned(
+            &input.ident,
+            "VectorOps can only be derived for structs"
+        )),
+    };
+
+    let members: Vec<(proc_macro2::TokenStream, &Type)> = match fields {
+        Fields::Named(named) => named.named.iter()
+            .map(|field| (field.ident.to_token_stream(), &field.ty))
+            .collect(),
+        Fields::Unnamed(unnamed) => unnamed.unnamed.iter().enumerate()
+            .map(|(idx, field)| (Index::from(idx).to_token_stream(), &field.ty))
+            .collect(),
+        Fields::Unit => Vec::new(),
+    };
+
+    let position = match field_name {
+        Some(Lit::Str(name)) => members.iter()
+            .position(|(member, _)| member.to_string() == name.value())
+            .ok_or_else(|| syn::Error::new_spanned(name, "no such field"))?,
+        Some(other) => return Err(syn::Error::new_spanned(
+            other,
+            "expected the field name as a string"
+        )),
+        None if members.len() == 1 => 0,
+        None => return Err(syn::Error::new_spanned(
+            &input.ident,
+            "the field holding the elements must be named with `#[vector_ops(field = \"..\")]`"
+        )),
+    };
+
+    let (field, field_ty) = members[position].clone();
+
+    // An array field makes a fixed-size vector.
+    let (elem, length) = match field_ty {
+        Type::Array(array) => (&*array.elem, Some(array.len.to_token_stream())),
+        _ => (
+            element_type(field_ty).ok_or_else(|| syn::Error::new_spanned(
+                field_ty,
+                "expected a `Vec<T>`, slice-like or array field"
+            ))?,
+            None
+        ),
+    };
+
+    // The operators build new vectors from the elements alone, so any
+    // other field could only be filled with a value unrelated to the operands,
+    // apart from markers which hold no data.
+    let errors: Vec<syn::Error> = members.iter().enumerate()
+        .filter(|(idx, (_, ty))| *idx != position && !is_phantom_data(ty))
+        .map(|(_, (_, ty))| syn::Error::new_spanned(
+            ty,
+            "VectorOps cannot fill fields other than the elements, apart from `PhantomData` markers"
+        ))
+        .collect();
+    combine_errors(errors)?;
+
+    // Every marker is filled with its default value.
+    let values = members.iter().enumerate().map(|(idx, (member, _))| {
+        let value = if idx == position {
+            quote!(params.into())
+        } else {
+            quote!(::core::default::Default::default())
+        };
+        (member, value)
+    });
+
+    let name = &input.ident;
+    let construct = match fields {
+        Fields::Named(_) => {
+            let values = values.map(|(member, value)| quote!(#member: #value));
+            quote!(#name { #(#values),* })
+        },
+        _ => {
+            let values = values.map(|(_, value)| value);
+            quote!(#name(#(#values),*))
+        },
+    };
+
+    // The impl generics are reparsed for their parameters without any defaults,
+    // such as `T` for `T = f32`.
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let params = syn::parse2::<Generics>(impl_generics.to_token_stream())?.params;
+
+    Ok(VectorTarget {
+        ty: quote!(#name #ty_generics),
+        rhs_ty: quote!(#name #ty_generics),
+        rhs_elem: elem.to_token_stream(),
+        params: quote!(#params),
+        predicates: where_clause
+            .map(|clause| clause.predicates.iter().map(ToTokens::to_token_stream).collect())
+            .unwrap_or_default(),
+        elem: elem.to_token_stream(),
+        field,
+        construct,
+        length,
+        parallel_threshold: parallel::DEFAULT_THRESHOLD,
+    })
+}
+
+pub fn vector_ops(input : DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let attr = parse_attrs(&input)?;
+    let target = vector_target(&input, attr.field.as_ref())?;
+    let target = VectorTarget {
+        parallel_threshold: attr.parallel_threshold.unwrap_or(target.parallel_threshold),
+        ..target
+    };
+
+    let mut implementations = proc_macro2::TokenStream::new();
+
+    for op in attr.ops.iter() {
+        implementations.extend(operator_impls(op, &target, false)?);
+    }
+
+    for scalar in attr.scalars.iter() {
+        match target.instantiate(&parse_quote!(#scalar))? {
+            Some(target) => implementations.extend(left_scalar_mul_impl(&target, false)),
+            None => return Err(scalar_vector_mul_error(&input.ident)),
+        }
+    }
+
+    // The operator traits are brought into scope here,
+    // so the deriving module does not need to import them.
+    Ok(quote!{
+        const _: () = {
+            use ::core::ops::*;
+
+            #implementations
+        };
+    })
+}
@@ -0,0 +1,399 @@
+use quote::quote;
+
+use crate::{ElementwiseOp, VectorTarget};
+
+/// The binary expression nodes along with their operator and documentation.
+fn binary_nodes() -> [(proc_macro2::TokenStream, ElementwiseOp, &'static str); 2] {
+    [
+        (quote!(AddExpr), ElementwiseOp::add(), " The lazy elementwise sum of two vector expressions, built by `+`."),
+        (quote!(SubExpr), ElementwiseOp::sub(), " The lazy elementwise difference of two vector expressions, built by `-`."),
+    ]
+}
+
+/// The expression nodes as types with their generic parameters,
+/// such as `AddExpr<L, R>`, along with these parameters.
+fn node_types() -> Vec<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
+    let mut nodes: Vec<_> = binary_nodes().into_iter()
+        .map(|(node, ..)| (quote!(#node<L, R>), quote!(L, R)))
+        .collect();
+    nodes.push((quote!(ScaleExpr<E, S>), quote!(E, S)));
+    nodes
+}
+
+/// The `VectorExpr` and `FromVectorExpr` traits and the expression nodes,
+/// along with the operators combining the nodes into larger expressions.
+pub fn expr_traits() -> proc_macro2::TokenStream {
+    let binary = binary_nodes().into_iter().map(|(node, op, doc)| {
+        let ElementwiseOp {
+            trait_name,
+            operator,
+            mismatch_msg,
+            ..
+        } = op;
+
+        quote!{
+            #[doc = #doc]
+            #[derive(Debug, Clone, Copy)]
+            pub struct #node<L, R> {
+                lhs : L,
+                rhs : R,
+            }
+
+            impl<L: VectorExpr, R: VectorExpr> #node<L, R> {
+                /// Combines `lhs` and `rhs`, which must be the same size.
+                ///
+                /// # Panic!
+                /// This function will panic if the expressions are not the same size.
+                pub fn new(lhs: L, rhs: R) -> Self {
+                    if lhs.len() != rhs.len() {
+                        panic!(#mismatch_msg)
+                    }
+
+                    #node { lhs, rhs }
+                }
+            }
+
+            // Safety: `new` checked that both sides have the same length,
+            // which their own contract keeps stable.
+            unsafe impl<L, R> VectorExpr for #node<L, R>
+            where
+                L: VectorExpr,
+                R: VectorExpr<Elem = L::Elem, Vector = L::Vector>,
+                L::Elem: ::core::ops::#trait_name<Output = L::Elem>,
+            {
+                type Elem = L::Elem;
+                type Vector = L::Vector;
+
+                fn len(&self) -> usize {
+                    self.lhs.len()
+                }
+
+                unsafe fn at_unchecked(&self, idx: usize) -> Self::Elem {
+                    // Safety: both sides have the length of `self`.
+                    unsafe { self.lhs.at_unchecked(idx) #operator self.rhs.at_unchecked(idx) }
+                }
+            }
+        }
+    });
+
+    let operators = node_types().into_iter().map(|(node, params)| {
+        let binary = binary_nodes().into_iter().map(|(expr, op, _)| {
+            let ElementwiseOp {
+                trait_name,
+                method,
+                ..
+            } = op;
+
+            quote!{
+                impl<#params, Rhs> ::core::ops::#trait_name<Rhs> for #node
+                where
+                    Self: VectorExpr,
+                    Rhs: VectorExpr<Elem = <Self as VectorExpr>::Elem, Vector = <Self as VectorExpr>::Vector>,
+                    <Self as VectorExpr>::Elem: ::core::ops::#trait_name<Output = <Self as VectorExpr>::Elem>,
+                {
+                    type Output = #expr<Self, Rhs>;
+
+                    fn #method(self, rhs: Rhs) -> Self::Output {
+                        #expr::new(self, rhs)
+                    }
+                }
+            }
+        });
+
+        quote!{
+            #(#binary)*
+
+            impl<#params, Scalar> ::core::ops::Mul<Scalar> for #node
+            where
+                Self: VectorExpr<Elem = Scalar>,
+                Scalar: Copy + ::core::ops::Mul<Output = Scalar>,
+            {
+                type Output = ScaleExpr<Self, Scalar>;
+
+                fn mul(self, rhs: Scalar) -> Self::Output {
+                    ScaleExpr::new(self, rhs)
+                }
+            }
+
+            impl<#params, V> PartialEq<V> for #node
+            where
+                Self: VectorExpr<Vector = V>,
+                V: FromVectorExpr<<Self as VectorExpr>::Elem>,
+                <Self as VectorExpr>::Elem: PartialEq,
+            {
+                fn eq(&self, other: &V) -> bool {
+                    other.eq_expr(self)
+                }
+            }
+        }
+    });
+
+    quote!{
+        /// An elementwise vector expression, such as `&a + &b * 2`,
+        /// which is only evaluated once it is collected with `eval`,
+        /// assigned with `eval_into` or compared with `==`,
+        /// in one loop over the elements.
+        ///
+        /// # Safety
+        /// The evaluation reads the elements with `at_unchecked` without checking
+        /// the indices again, so an implementation must return the same `len`
+        /// for as long as it exists, and `at_unchecked` must be sound for every
+        /// index less than this length.
+        // Clippy does not find the section in the docs emitted by a macro.
+        #[allow(clippy::missing_safety_doc)]
+        pub unsafe trait VectorExpr {
+            /// The type of the elements.
+            type Elem;
+            /// The vector type the expression is evaluated into.
+            type Vector: FromVectorExpr<Self::Elem>;
+
+            /// The number of elements.
+            fn len(&self) -> usize;
+
+            /// Whether the expression has no elements.
+            fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// The element at `idx`.
+            ///
+            /// # Panic!
+            /// This function will panic if `idx` is not less than the length.
+            fn at(&self, idx: usize) -> Self::Elem {
+                assert!(idx < self.len(), "The index {} is out of bounds.", idx);
+
+                // Safety: `idx` is less than the length.
+                unsafe { self.at_unchecked(idx) }
+            }
+
+            /// The element at `idx`, without checking it against the length.
+            ///
+            /// # Safety
+            /// `idx` must be less than the length.
+            unsafe fn at_unchecked(&self, idx: usize) -> Self::Elem;
+
+            /// Evaluates the expression into a new vector.
+            fn eval(&self) -> Self::Vector
+            where
+                Self: Sized
+            {
+                <Self::Vector as FromVectorExpr<Self::Elem>>::from_expr(self)
+            }
+
+            /// Evaluates the expression into `vector`, which must be the same size.
+            fn eval_into(&self, vector: &mut Self::Vector)
+            where
+                Self: Sized
+            {
+                vector.assign_expr(self)
+            }
+        }
+
+        /// A vector type into which the vector expressions
+        /// with elements of type `T` are evaluated.
+        pub trait FromVectorExpr<T>: Sized {
+            /// Builds a vector from the elements of `expr`.
+            fn from_expr<E: VectorExpr<Elem = T>>(expr: &E) -> Self;
+
+            /// Overwrites the elements with those of `expr`,
+            /// which must be the same size.
+            fn assign_expr<E: VectorExpr<Elem = T>>(&mut self, expr: &E);
+
+            /// Whether the elements are equal to those of `expr`.
+            fn eq_expr<E: VectorExpr<Elem = T>>(&self, expr: &E) -> bool
+            where
+                T: PartialEq;
+        }
+
+        #(#binary)*
+
+        /// The lazy product of a vector expression and a scalar, built by `*`.
+        #[derive(Debug, Clone, Copy)]
+        pub struct ScaleExpr<E, S> {
+            expr : E,
+            scalar : S,
+        }
+
+        impl<E: VectorExpr, S> ScaleExpr<E, S> {
+            /// Scales `expr` by `scalar`.
+            pub fn new(expr: E, scalar: S) -> Self {
+                ScaleExpr { expr, scalar }
+            }
+        }
+
+        // Safety: the expression has the same length, which its own contract keeps stable.
+        unsafe impl<E, S> VectorExpr for ScaleExpr<E, S>
+        where
+            E: VectorExpr<Elem = S>,
+            S: Copy + ::core::ops::Mul<Output = S>,
+        {
+            type Elem = S;
+            type Vector = E::Vector;
+
+            fn len(&self) -> usize {
+                self.expr.len()
+            }
+
+            unsafe fn at_unchecked(&self, idx: usize) -> Self::Elem {
+                // Safety: the expression has the length of `self`.
+                // The scalar is the left factor, as in `vector_scalar_mul_impl!`.
+                self.scalar * unsafe { self.expr.at_unchecked(idx) }
+            }
+        }
+
+        #(#operators)*
+    }
+}
+
+/// The lazy operators of the borrowed vectors of the `target`,
+/// which build the expression nodes of [expr_traits],
+/// and the evaluation of the expressions into the vector type.
+pub fn expr_impl(
+    target : &VectorTarget,
+    add_documentation : proc_macro2::TokenStream,
+    sub_documentation : proc_macro2::TokenStream,
+    mul_documentation : proc_macro2::TokenStream
+) -> proc_macro2::TokenStream {
+    let VectorTarget {
+        ty,
+        params,
+        predicates,
+        elem,
+        field,
+        construct,
+        length,
+        ..
+    } = target;
+
+    let lifetime = target.borrow_lifetime();
+    // The parameters are followed by those of the nodes,
+    // with a comma only in between, as they may be empty.
+    let leading_params = if params.is_empty() { quote!() } else { quote!(#params,) };
+    let assign_msg = "Vectors with different sizes cannot be assigned to each other.";
+
+    let build = match length {
+        Some(length) => quote!{
+            if expr.len() != #length {
+                panic!(#assign_msg)
+            }
+
+            // Safety: every index is less than the checked length.
+            let params: [#elem; #length] = ::core::array::from_fn(|idx| unsafe { expr.at_unchecked(idx) });
+
+            #construct
+        },
+        None => quote!{
+            // Safety: every index is less than the length.
+            let params: Vec<#elem> = (0..expr.len())
+                .map(|idx| unsafe { expr.at_unchecked(idx) })
+                .collect();
+
+            #construct
+        }
+    };
+
+    let binary = binary_nodes().into_iter().zip([add_documentation, sub_documentation]).map(|((node, op, _), documentation)| {
+        let ElementwiseOp {
+            trait_name,
+            method,
+            ..
+        } = op;
+
+        quote!{
+            #documentation
+            impl<#lifetime, #leading_params Rhs> #trait_name<Rhs> for &#lifetime #ty
+            where
+                #elem: Copy + #trait_name<Output = #elem>,
+                Rhs: VectorExpr<Elem = #elem, Vector = #ty>,
+                #(#predicates,)*
+            {
+                type Output = #node<&#lifetime #ty, Rhs>;
+
+                fn #method(self, rhs: Rhs) -> Self::Output {
+                    #node::new(self, rhs)
+                }
+            }
+        }
+    });
+
+    let comparisons = node_types().into_iter().map(|(node, node_params)| quote!{
+        impl<#leading_params #node_params> PartialEq<#node> for #ty
+        where
+            #node: VectorExpr<Elem = #elem, Vector = #ty>,
+            #elem: PartialEq,
+            #(#predicates,)*
+        {
+            fn eq(&self, other: &#node) -> bool {
+                self.eq_expr(other)
+            }
+        }
+    });
+
+    quote!{
+        // Safety: the vector is borrowed, so its length cannot change
+        // while it is read.
+        unsafe impl<#lifetime, #params> VectorExpr for &#lifetime #ty
+        where
+            #elem: Copy,
+            #(#predicates,)*
+        {
+            type Elem = #elem;
+            type Vector = #ty;
+
+            fn len(&self) -> usize {
+                self.#field.len()
+            }
+
+            unsafe fn at_unchecked(&self, idx: usize) -> Self::Elem {
+                // Safety: the caller guarantees that `idx` is less than the length.
+                unsafe { *self.#field.get_unchecked(idx) }
+            }
+        }
+
+        impl<#params> FromVectorExpr<#elem> for #ty
+        where
+            #(#predicates,)*
+        {
+            fn from_expr<E: VectorExpr<Elem = #elem>>(expr: &E) -> Self {
+                #build
+            }
+
+            fn assign_expr<E: VectorExpr<Elem = #elem>>(&mut self, expr: &E) {
+                if self.#field.len() != expr.len() {
+                    panic!(#assign_msg)
+                }
+
+                // Safety: every index is less than the length of both.
+                for (idx, item) in self.#field.iter_mut().enumerate() {
+                    *item = unsafe { expr.at_unchecked(idx) }
+                }
+            }
+
+            fn eq_expr<E: VectorExpr<Elem = #elem>>(&self, expr: &E) -> bool
+            where
+                #elem: PartialEq
+            {
+                // Safety: every index is less than the length of both.
+                self.#field.len() == expr.len()
+                    && self.#field.iter().enumerate().all(|(idx, item)| *item == unsafe { expr.at_unchecked(idx) })
+            }
+        }
+
+        #(#binary)*
+
+        #mul_documentation
+        impl<#lifetime, #params> Mul<#elem> for &#lifetime #ty
+        where
+            #elem: Copy + Mul<Output = #elem>,
+            #(#predicates,)*
+        {
+            type Output = ScaleExpr<&#lifetime #ty, #elem>;
+
+            fn mul(self, rhs: #elem) -> Self::Output {
+                ScaleExpr::new(self, rhs)
+            }
+        }
+
+        #(#comparisons)*
+    }
+}
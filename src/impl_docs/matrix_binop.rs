@@ -0,0 +1,108 @@
+use quote::quote;
+
+/// The example values shown in the generated documentation
+/// of a matrix operator.
+pub struct OperatorExample {
+    pub lhs : &'static str,
+    pub rhs : &'static str,
+    pub result : &'static str,
+    /// When the operator panics.
+    pub panic : &'static str,
+}
+
+pub fn matrix_binop_impl_doc(
+    name : &str,
+    trait_name : &str,
+    operator : &str,
+    example : &OperatorExample,
+    types_state : (bool, bool, bool, bool)
+) -> proc_macro2::TokenStream {
+    let (lhs_borrow, lhs_mut, rhs_borrow, rhs_mut) = types_state;
+
+    let (lhs_name, lhs_expr) = match (lhs_borrow, lhs_mut) {
+        (true, true) => ("&mut Matrix", "&mut matrix1"),
+        (true, false) => ("&Matrix", "&matrix1"),
+        (false, false) => ("Matrix", "matrix1"),
+        _ => unreachable!()
+    };
+
+    let (rhs_name, rhs_expr) = match (rhs_borrow, rhs_mut) {
+        (true, true) => ("&mut Matrix", "&mut matrix2"),
+        (true, false) => ("&Matrix", "&matrix2"),
+        (false, false) => ("Matrix", "matrix2"),
+        _ => unreachable!()
+    };
+
+    let panic = format!(" This function will panic if {}.", example.panic);
+
+    let summary = format!(
+        " The [{name}][std::ops::{trait_name}] implementation for '{lhs_name} {operator} {rhs_name}'."
+    );
+
+    let lhs_decl = format!(
+        " let {}matrix1 = matrix![{}];",
+        if lhs_mut { "mut " } else { "" },
+        example.lhs
+    );
+    let rhs_decl = format!(
+        " let {}matrix2 = matrix![{}];",
+        if rhs_mut { "mut " } else { "" },
+        example.rhs
+    );
+
+    let moved = match (lhs_borrow, rhs_borrow) {
+        (false, false) => " // Notice that both matrices are moved here",
+        (false, true) => " // Notice that 'matrix1' is moved here",
+        (true, false) => " // Notice that 'matrix2' is moved here",
+        (true, true) => "",
+    };
+
+    let expression = format!("{lhs_expr} {operator} {rhs_expr}");
+    let (operation, assertion) = if lhs_mut {
+        (format!(" {expression};"), format!(" assert_eq!(matrix1, matrix![{}])", example.result))
+    } else if rhs_mut {
+        (format!(" {expression};"), format!(" assert_eq!(matrix2, matrix![{}])", example.result))
+    } else {
+        (format!(" let matrix3 = {expression};"), format!(" assert_eq!(matrix3, matrix![{}])", example.result))
+    };
+
+    let moved = if moved.is_empty() {
+        quote!()
+    } else {
+        quote!(#[doc = #moved])
+    };
+
+    let warning = if lhs_mut && rhs_mut {
+        quote!{
+            ///
+            /// # Warning
+            /// While the right hand side is mutable,
+            /// nothing will be mutated on the right hand side.
+            /// All changes will happen to the left hand side.
+        }
+    } else {
+        quote!()
+    };
+
+    quote!{
+        #[doc = #summary]
+        #warning
+        ///
+        /// # Example
+        /// ```
+        /// use simp_linalg::matrix_impl::prelude::*;
+        ///
+        #[doc = #lhs_decl]
+        #[doc = #rhs_decl]
+        ///
+        #moved
+        #[doc = #operation]
+        ///
+        #[doc = #assertion]
+        /// ```
+        ///
+        /// # Panic!
+        ///
+        #[doc = #panic]
+    }
+}
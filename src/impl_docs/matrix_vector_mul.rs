@@ -0,0 +1,118 @@
+use quote::quote;
+
+pub fn matrix_vector_mul_impl_doc(matrix_on_left : bool, types_state : (bool, bool, bool, bool)) -> proc_macro2::TokenStream {
+    let (lhs_borrow, lhs_mut, rhs_borrow, rhs_mut) = types_state;
+
+    let operand = |borrow : bool, mutable : bool, name : &str| match (borrow, mutable) {
+        (true, true) => (format!("&mut {}", capitalize(name)), format!("&mut {name}")),
+        (true, false) => (format!("&{}", capitalize(name)), format!("&{name}")),
+        (false, false) => (capitalize(name), name.to_string()),
+        _ => unreachable!()
+    };
+
+    let (lhs, rhs) = if matrix_on_left { ("matrix", "vector") } else { ("vector", "matrix") };
+    let (lhs_name, lhs_expr) = operand(lhs_borrow, lhs_mut, lhs);
+    let (rhs_name, rhs_expr) = operand(rhs_borrow, rhs_mut, rhs);
+
+    let summary = format!(
+        " The [multiplication][std::ops::Mul] implementation for '{lhs_name} * {rhs_name}'."
+    );
+
+    let description = if matrix_on_left {
+        " This calculates the product of the matrix and the column vector."
+    } else {
+        " This calculates the product of the row vector and the matrix."
+    };
+
+    let vector_mut = if matrix_on_left { rhs_mut } else { lhs_mut };
+
+    let matrix_decl = format!(
+        " let {}matrix = matrix![[1, 2], [3, 4]];",
+        if (matrix_on_left && lhs_mut) || (!matrix_on_left && rhs_mut) { "mut " } else { "" }
+    );
+    let vector_decl = format!(
+        " let {}vector = vector![5, 6];",
+        if vector_mut { "mut " } else { "" }
+    );
+
+    let expected = if matrix_on_left { "vector![17, 39]" } else { "vector![23, 34]" };
+
+    let expression = format!("{lhs_expr} * {rhs_expr}");
+    let (operation, assertion) = if vector_mut {
+        (format!(" {expression};"), format!(" assert_eq!(vector, {expected})"))
+    } else {
+        (format!(" let result = {expression};"), format!(" assert_eq!(result, {expected})"))
+    };
+
+    let moved = match (lhs_borrow, rhs_borrow) {
+        (false, false) => format!(" // Notice that both the {lhs} and the {rhs} are moved here"),
+        (false, true) => format!(" // Notice that '{lhs}' is moved here"),
+        (true, false) => format!(" // Notice that '{rhs}' is moved here"),
+        (true, true) => String::new(),
+    };
+
+    let moved = if moved.is_empty() {
+        quote!()
+    } else {
+        quote!(#[doc = #moved])
+    };
+
+    let in_place = if vector_mut {
+        quote!{
+            ///
+            /// The product is written into the vector, which is then returned.
+        }
+    } else {
+        quote!()
+    };
+
+    let warning = if (matrix_on_left && lhs_mut) || (!matrix_on_left && rhs_mut) {
+        quote!{
+            ///
+            /// # Warning
+            /// While the matrix is mutable,
+            /// nothing will be mutated in the matrix.
+        }
+    } else {
+        quote!()
+    };
+
+    let panic = if matrix_on_left {
+        " This function will panic if the number of columns of the matrix differs from the length of the vector."
+    } else {
+        " This function will panic if the length of the vector differs from the number of rows of the matrix."
+    };
+
+    quote!{
+        #[doc = #summary]
+        ///
+        #[doc = #description]
+        #in_place
+        #warning
+        ///
+        /// # Example
+        /// ```
+        /// use simp_linalg::matrix_impl::prelude::*;
+        ///
+        #[doc = #matrix_decl]
+        #[doc = #vector_decl]
+        ///
+        #moved
+        #[doc = #operation]
+        ///
+        #[doc = #assertion]
+        /// ```
+        ///
+        /// # Panic!
+        ///
+        #[doc = #panic]
+    }
+}
+
+fn capitalize(name : &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -1,3 +1,18 @@
 pub mod vector_add;
+pub mod vector_sub;
+pub mod vector_binop;
+pub mod vector_elementwise;
+pub mod vector_try_binop;
+pub mod vector_add_assign;
+pub mod vector_sub_assign;
 pub mod vector_dot_prod;
-pub mod vector_scalar_mul;
\ No newline at end of file
+pub mod vector_dot_as;
+pub mod vector_expr;
+pub mod vector_scalar_mul;
+pub mod vector_scalar_div;
+pub mod vector_scalar_rem;
+pub mod vector_scalar_mul_assign;
+pub mod scalar_vector_mul;
+pub mod vector_neg;
+pub mod matrix_binop;
+pub mod matrix_vector_mul;
\ No newline at end of file
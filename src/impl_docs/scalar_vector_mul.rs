@@ -0,0 +1,81 @@
+use quote::{quote, ToTokens};
+use syn::Type;
+
+pub fn scalar_vector_mul_impl_doc(type_state : (bool, bool), scalar : &Type) -> proc_macro2::TokenStream {
+    let scalar = scalar.to_token_stream().to_string();
+
+    let vector = format!(
+        " let {}vector = vector![1 as {scalar}, 2 as {scalar}, 3 as {scalar}];",
+        if type_state == (true, true) { "mut " } else { "" }
+    );
+    let expected = format!(" vector![3 as {scalar}, 6 as {scalar}, 9 as {scalar}]");
+
+    match type_state {
+
+        //T * &mut Vector<T>
+        (true, true) => {
+            let summary = format!(" The [multiplication][std::ops::Mul] implementation for '{scalar} * &mut Vector<{scalar}>'.");
+            let operation = format!(" 3 as {scalar} * &mut vector;");
+            let assertion = format!(" assert_eq!(vector,{expected})");
+
+            quote!{
+                #[doc = #summary]
+                ///
+                /// The vector is scaled in place.
+                ///
+                /// # Example
+                /// ```
+                /// use simp_linalg::vector_impl::prelude::*;
+                ///
+                #[doc = #vector]
+                ///
+                #[doc = #operation]
+                ///
+                #[doc = #assertion]
+                /// ```
+            }
+        },
+
+        //T * &Vector<T>
+        (true, false) => {
+            let summary = format!(" The [multiplication][std::ops::Mul] implementation for '{scalar} * &Vector<{scalar}>'.");
+            let assertion = format!(" assert_eq!(3 as {scalar} * &vector,{expected})");
+
+            quote!{
+                #[doc = #summary]
+                ///
+                /// # Example
+                /// ```
+                /// use simp_linalg::vector_impl::prelude::*;
+                ///
+                #[doc = #vector]
+                ///
+                #[doc = #assertion]
+                /// ```
+            }
+        },
+
+        //T * Vector<T>
+        (false, false) => {
+            let summary = format!(" The [multiplication][std::ops::Mul] implementation for '{scalar} * Vector<{scalar}>'.");
+            let assertion = format!(" assert_eq!(3 as {scalar} * vector,{expected})");
+
+            quote!{
+                #[doc = #summary]
+                ///
+                /// The memory of the vector is reused for the result.
+                ///
+                /// # Example
+                /// ```
+                /// use simp_linalg::vector_impl::prelude::*;
+                ///
+                #[doc = #vector]
+                ///
+                /// // Notice that the vector is moved here.
+                #[doc = #assertion]
+                /// ```
+            }
+        },
+        _ => unreachable!()
+    }
+}
\ No newline at end of file
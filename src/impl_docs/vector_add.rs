@@ -275,6 +275,6 @@ pub fn vector_add_impl_doc(types_state : (bool, bool, bool, bool)) -> proc_macro
             /// This function will panic if the vectors are not the same size.
         },
 
-        _ => panic!("Not supported")
+        _ => unreachable!()
     }
 }
\ No newline at end of file
@@ -0,0 +1,54 @@
+use quote::quote;
+
+use crate::ElementwiseOp;
+
+pub fn vector_binop_impl_doc(op : &ElementwiseOp, types_state : (bool, bool, bool, bool)) -> proc_macro2::TokenStream {
+    let trait_name = &op.trait_name;
+    let operator = &op.operator;
+
+    let (lhs, rhs) = match types_state {
+        (true, true, true, true) => ("&mut Vector", "&mut Vector"),
+        (true, true, true, false) => ("&mut Vector", "&Vector"),
+        (true, true, false, false) => ("&mut Vector", "Vector"),
+        (true, false, true, true) => ("&Vector", "&mut Vector"),
+        (false, false, true, true) => ("Vector", "&mut Vector"),
+        (true, false, true, false) => ("&Vector", "&Vector"),
+        (true, false, false, false) => ("&Vector", "Vector"),
+        (false, false, true, false) => ("Vector", "&Vector"),
+        (false, false, false, false) => ("Vector", "Vector"),
+        _ => unreachable!()
+    };
+
+    let summary = format!(
+        " The elementwise [{}] implementation for '{} {} {}'.",
+        trait_name, lhs, operator, rhs
+    );
+
+    let result = match types_state {
+        (true, true, _, _) => " The result is written into the left hand side, which is then returned.",
+        (_, _, true, true) => " The result is written into the right hand side, which is then returned.",
+        _ => " The result is a new Vector.",
+    };
+
+    let warning = match types_state {
+        (true, true, true, true) => quote!{
+            ///
+            /// # Warning
+            /// While the right hand side is mutable,
+            /// nothing will be mutated on the right hand side.
+            /// All changes will happen to the left hand side.
+        },
+        _ => quote!()
+    };
+
+    quote!{
+        #[doc = #summary]
+        ///
+        #[doc = #result]
+        #warning
+        ///
+        /// # Panic!
+        ///
+        /// This function will panic if the vectors are not the same size.
+    }
+}
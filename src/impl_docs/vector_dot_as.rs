@@ -0,0 +1,83 @@
+use quote::quote;
+
+pub fn vector_dot_as_impl_doc(types_state : (bool, bool, bool, bool)) -> proc_macro2::TokenStream {
+    let (lhs_borrow, lhs_mut, rhs_borrow, rhs_mut) = types_state;
+
+    let (lhs_name, lhs_expr) = match (lhs_borrow, lhs_mut) {
+        (true, true) => ("&mut Vector", "(&mut vector1)"),
+        (true, false) => ("&Vector", "(&vector1)"),
+        (false, false) => ("Vector", "vector1"),
+        _ => unreachable!()
+    };
+
+    let (rhs_name, rhs_expr) = match (rhs_borrow, rhs_mut) {
+        (true, true) => ("&mut Vector", "&mut vector2"),
+        (true, false) => ("&Vector", "&vector2"),
+        (false, false) => ("Vector", "vector2"),
+        _ => unreachable!()
+    };
+
+    let summary = format!(
+        " The [DotAs] implementation for '{lhs_name}.dot_as({rhs_name})'."
+    );
+
+    let lhs_decl = format!(
+        " let {}vector1 = vector![100i8, 100, 100];",
+        if lhs_mut { "mut " } else { "" }
+    );
+    let rhs_decl = format!(
+        " let {}vector2 = vector![100i8, 50, -100];",
+        if rhs_mut { "mut " } else { "" }
+    );
+
+    let moved = match (lhs_borrow, rhs_borrow) {
+        (false, false) => " // Notice that both vectors are moved here",
+        (false, true) => " // Notice that 'vector1' is moved here",
+        (true, false) => " // Notice that 'vector2' is moved here",
+        (true, true) => "",
+    };
+
+    let operation = format!(" let value = {lhs_expr}.dot_as::<i32>({rhs_expr});");
+
+    let moved = if moved.is_empty() {
+        quote!()
+    } else {
+        quote!(#[doc = #moved])
+    };
+
+    let warning = if lhs_mut || rhs_mut {
+        quote!{
+            ///
+            /// # Warning
+            /// While the vectors are mutable,
+            /// nothing will be mutated in either vector.
+        }
+    } else {
+        quote!()
+    };
+
+    quote!{
+        #[doc = #summary]
+        ///
+        /// This calculates the dot product of the two vectors,
+        /// summing the products in the chosen accumulator type,
+        /// which the products of `i8` below would overflow.
+        #warning
+        ///
+        /// # Example
+        /// ```
+        /// use simp_linalg::vector_impl::prelude::*;
+        ///
+        #[doc = #lhs_decl]
+        #[doc = #rhs_decl]
+        ///
+        #moved
+        #[doc = #operation]
+        ///
+        /// assert_eq!(value, 5000)
+        /// ```
+        ///
+        /// # Panic!
+        /// This function will panic if the vectors are not the same size.
+    }
+}
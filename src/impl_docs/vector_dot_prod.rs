@@ -119,6 +119,6 @@ pub fn vector_dot_prod_impl_doc(types_state : (bool, bool, bool, bool)) -> proc_
             /// This function will panic if the vectors are not the same size.
         },
 
-        _ => panic!("Not supported")
+        _ => unreachable!()
     }
 }
\ No newline at end of file
@@ -0,0 +1,107 @@
+use quote::quote;
+
+/// The example values shown in the generated documentation
+/// of a named elementwise method.
+pub struct MethodExample {
+    pub lhs : &'static str,
+    pub rhs : &'static str,
+    pub result : &'static str,
+    /// When the method panics.
+    pub panic : &'static str,
+}
+
+pub fn vector_elementwise_impl_doc(
+    trait_name : &str,
+    method : &str,
+    example : &MethodExample,
+    types_state : (bool, bool, bool, bool)
+) -> proc_macro2::TokenStream {
+    let (lhs_borrow, lhs_mut, rhs_borrow, rhs_mut) = types_state;
+
+    let (lhs_name, lhs_expr) = match (lhs_borrow, lhs_mut) {
+        (true, true) => ("&mut Vector", "(&mut vector1)"),
+        (true, false) => ("&Vector", "(&vector1)"),
+        (false, false) => ("Vector", "vector1"),
+        _ => unreachable!()
+    };
+
+    let (rhs_name, rhs_expr) = match (rhs_borrow, rhs_mut) {
+        (true, true) => ("&mut Vector", "&mut vector2"),
+        (true, false) => ("&Vector", "&vector2"),
+        (false, false) => ("Vector", "vector2"),
+        _ => unreachable!()
+    };
+
+    let panic = format!(" This function will panic if {}.", example.panic);
+
+    let summary = format!(
+        " The [{trait_name}] implementation for '{lhs_name}.{method}({rhs_name})'."
+    );
+
+    let lhs_decl = format!(
+        " let {}vector1 = vector![{}];",
+        if lhs_mut { "mut " } else { "" },
+        example.lhs
+    );
+    let rhs_decl = format!(
+        " let {}vector2 = vector![{}];",
+        if rhs_mut { "mut " } else { "" },
+        example.rhs
+    );
+
+    let moved = match (lhs_borrow, rhs_borrow) {
+        (false, false) => " // Notice that both vectors are moved here",
+        (false, true) => " // Notice that 'vector1' is moved here",
+        (true, false) => " // Notice that 'vector2' is moved here",
+        (true, true) => "",
+    };
+
+    let call = format!("{lhs_expr}.{method}({rhs_expr})");
+    let (operation, assertion) = if lhs_mut {
+        (format!(" {call};"), format!(" assert_eq!(vector1, vector![{}])", example.result))
+    } else if rhs_mut {
+        (format!(" {call};"), format!(" assert_eq!(vector2, vector![{}])", example.result))
+    } else {
+        (format!(" let vector3 = {call};"), format!(" assert_eq!(vector3, vector![{}])", example.result))
+    };
+
+    let moved = if moved.is_empty() {
+        quote!()
+    } else {
+        quote!(#[doc = #moved])
+    };
+
+    let warning = if lhs_mut && rhs_mut {
+        quote!{
+            ///
+            /// # Warning
+            /// While the right hand side is mutable,
+            /// nothing will be mutated on the right hand side.
+            /// All changes will happen to the left hand side.
+        }
+    } else {
+        quote!()
+    };
+
+    quote!{
+        #[doc = #summary]
+        #warning
+        ///
+        /// # Example
+        /// ```
+        /// use simp_linalg::vector_impl::prelude::*;
+        ///
+        #[doc = #lhs_decl]
+        #[doc = #rhs_decl]
+        ///
+        #moved
+        #[doc = #operation]
+        ///
+        #[doc = #assertion]
+        /// ```
+        ///
+        /// # Panic!
+        ///
+        #[doc = #panic]
+    }
+}
@@ -0,0 +1,55 @@
+use quote::quote;
+
+pub fn vector_expr_impl_doc(
+    trait_name : &str,
+    operation : &str,
+    node : &str,
+    expression : &str,
+    result : &str
+) -> proc_macro2::TokenStream {
+    let summary = format!(
+        " The lazy [{trait_name}][std::ops::{trait_name}] implementation for '{operation}'."
+    );
+
+    let description = format!(
+        " This builds a `{node}` rather than a new vector, which is evaluated"
+    );
+
+    let operation = format!(" let expr = {expression};");
+    let eq = format!(" assert_eq!(expr, vector![{result}]);");
+    let eval = format!(" assert_eq!(expr.eval(), vector![{result}])");
+
+    let panic = if node == "ScaleExpr" {
+        quote!()
+    } else {
+        quote!{
+            ///
+            /// # Panic!
+            /// This function will panic if the vectors are not the same size.
+        }
+    };
+
+    quote!{
+        #[doc = #summary]
+        ///
+        #[doc = #description]
+        /// along with the rest of the expression in one loop over the elements,
+        /// once it is collected with `eval`, assigned with `eval_into` or compared.
+        ///
+        /// # Example
+        /// ```
+        /// use simp_linalg::vector_impl::prelude::*;
+        ///
+        /// let vector1 = vector![1, 2, 3];
+        /// let vector2 = vector![4, 5, 6];
+        /// let vector3 = vector![7, 8, 9];
+        ///
+        /// // Nothing is computed until the expression is evaluated
+        #[doc = #operation]
+        ///
+        #[doc = #eq]
+        #[doc = #eval]
+        /// ```
+        #panic
+    }
+}
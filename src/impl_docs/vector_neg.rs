@@ -0,0 +1,56 @@
+use quote::quote;
+
+pub fn vector_neg_impl_doc(type_state : (bool, bool)) -> proc_macro2::TokenStream {
+    match type_state {
+
+        //-&mut Vector<T>
+        (true, true) => quote!{
+            /// The [negation][std::ops::Neg] implementation for '-&mut Vector'.
+            /// 
+            /// The vector is negated in place.
+            /// 
+            /// # Example
+            /// ```
+            /// use simp_linalg::vector_impl::prelude::*;
+            /// 
+            /// let mut vector = vector![1, -2, 3];
+            /// 
+            /// -&mut vector;
+            /// 
+            /// assert_eq!(vector, vector![-1, 2, -3])
+            /// ```
+        },
+
+        //-&Vector<T>
+        (true, false) => quote!{
+            /// The [negation][std::ops::Neg] implementation for '-&Vector'.
+            /// 
+            /// # Example
+            /// ```
+            /// use simp_linalg::vector_impl::prelude::*;
+            /// 
+            /// let vector = vector![1, -2, 3];
+            /// 
+            /// assert_eq!(-&vector, vector![-1, 2, -3])
+            /// ```
+        },
+
+        //-Vector<T>
+        (false, false) => quote!{
+            /// The [negation][std::ops::Neg] implementation for '-Vector'.
+            /// 
+            /// The memory of the vector is reused for the result.
+            /// 
+            /// # Example
+            /// ```
+            /// use simp_linalg::vector_impl::prelude::*;
+            /// 
+            /// let vector = vector![1, -2, 3];
+            /// 
+            /// // Notice that the vector is moved here.
+            /// assert_eq!(-vector, vector![-1, 2, -3])
+            /// ```
+        },
+        _ => unreachable!()
+    }
+}
\ No newline at end of file
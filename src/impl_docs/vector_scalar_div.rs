@@ -0,0 +1,62 @@
+use quote::quote;
+
+pub fn vector_scalar_div_impl_doc(type_state : (bool, bool)) -> proc_macro2::TokenStream {
+    match type_state {
+
+        //&mut Vector<T> / T
+        (true, true) => quote!{
+            /// The [division][std::ops::Div] implementation for '&mut Vector / T'.
+            /// 
+            /// For integer element types, each element is
+            /// divided with the usual integer division.
+            /// 
+            /// # Example
+            /// ```
+            /// use simp_linalg::vector_impl::prelude::*;
+            /// 
+            /// let mut vector = vector![3, 6, 9];
+            /// 
+            /// &mut vector / 3;
+            /// 
+            /// assert_eq!(vector, vector![1, 2, 3])
+            /// ```
+        },
+
+        //&Vector<T> / T
+        (true, false) => quote!{
+            /// The [division][std::ops::Div] implementation for '&Vector / T'.
+            /// 
+            /// For integer element types, each element is
+            /// divided with the usual integer division.
+            /// 
+            /// # Example
+            /// ```
+            /// use simp_linalg::vector_impl::Vector;
+            /// use simp_linalg::vector;
+            /// 
+            /// let vector = vector![3, 6, 9];
+            /// 
+            /// assert_eq!(&vector / 3, vector![1, 2, 3])
+            /// ```
+        },
+
+        //Vector<T> / T
+        (false, false) => quote!{
+            /// The [division][std::ops::Div] implementation for 'Vector / T'.
+            /// 
+            /// For integer element types, each element is
+            /// divided with the usual integer division.
+            /// 
+            /// # Example
+            /// ```
+            /// use simp_linalg::vector_impl::prelude::*;
+            /// 
+            /// let vector = vector![3, 6, 9];
+            /// 
+            /// // Notice that the vector is moved here.
+            /// assert_eq!(vector / 3, vector![1, 2, 3])
+            /// ```
+        },
+        _ => unreachable!()
+    }
+}
\ No newline at end of file
@@ -7,8 +7,9 @@ pub fn vector_scalar_mul_impl_doc(type_state : (bool, bool)) -> proc_macro2::Tok
         (true, true) => quote!{
             /// The [multiplication][std::ops::Mul] implementation for '&mut Vector * T'.
             /// 
-            /// In contrast to common mathematical notation,
-            /// the scalar must be on the right of the vector.
+            /// The scalar can also be on the left of the vector, as in
+            /// common mathematical notation, for the primitive element types
+            /// given to `scalar_vector_mul_impl!`.
             /// 
             /// # Example
             /// ```
@@ -26,8 +27,9 @@ pub fn vector_scalar_mul_impl_doc(type_state : (bool, bool)) -> proc_macro2::Tok
         (true, false) => quote!{
             /// The [multiplication][std::ops::Mul] implementation for '&Vector * T'.
             /// 
-            /// In contrast to common mathematical notation,
-            /// the scalar must be on the right of the vector.
+            /// The scalar can also be on the left of the vector, as in
+            /// common mathematical notation, for the primitive element types
+            /// given to `scalar_vector_mul_impl!`.
             /// 
             /// # Example
             /// ```
@@ -44,8 +46,9 @@ pub fn vector_scalar_mul_impl_doc(type_state : (bool, bool)) -> proc_macro2::Tok
         (false, false) => quote!{
             /// The [multiplication][std::ops::Mul] implementation for 'Vector * T'.
             /// 
-            /// In contrast to common mathematical notation,
-            /// the scalar must be on the right of the vector.
+            /// The scalar can also be on the left of the vector, as in
+            /// common mathematical notation, for the primitive element types
+            /// given to `scalar_vector_mul_impl!`.
             /// 
             /// # Example
             /// ```
@@ -57,6 +60,6 @@ pub fn vector_scalar_mul_impl_doc(type_state : (bool, bool)) -> proc_macro2::Tok
             /// assert_eq!(vector * 3, vector![3, 6, 9])
             /// ```
         },
-        _ => panic!("Not supported")
+        _ => unreachable!()
     }
 }
\ No newline at end of file
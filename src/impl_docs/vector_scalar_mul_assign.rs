@@ -0,0 +1,23 @@
+use quote::quote;
+
+pub fn vector_scalar_mul_assign_impl_doc(type_state : (bool, bool)) -> proc_macro2::TokenStream {
+    match type_state {
+
+        //Vector<T> *= T
+        (false, false) => quote!{
+            /// The [multiplication assignment][std::ops::MulAssign] implementation for 'Vector *= T'.
+            /// 
+            /// # Example
+            /// ```
+            /// use simp_linalg::vector_impl::prelude::*;
+            /// 
+            /// let mut vector = vector![1, 2, 3];
+            /// 
+            /// vector *= 3;
+            /// 
+            /// assert_eq!(vector, vector![3, 6, 9])
+            /// ```
+        },
+        _ => unreachable!()
+    }
+}
\ No newline at end of file
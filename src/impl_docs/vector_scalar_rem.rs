@@ -0,0 +1,62 @@
+use quote::quote;
+
+pub fn vector_scalar_rem_impl_doc(type_state : (bool, bool)) -> proc_macro2::TokenStream {
+    match type_state {
+
+        //&mut Vector<T> % T
+        (true, true) => quote!{
+            /// The [remainder][std::ops::Rem] implementation for '&mut Vector % T'.
+            /// 
+            /// The remainder is taken of each element
+            /// divided by the scalar.
+            /// 
+            /// # Example
+            /// ```
+            /// use simp_linalg::vector_impl::prelude::*;
+            /// 
+            /// let mut vector = vector![4, 5, 6];
+            /// 
+            /// &mut vector % 3;
+            /// 
+            /// assert_eq!(vector, vector![1, 2, 0])
+            /// ```
+        },
+
+        //&Vector<T> % T
+        (true, false) => quote!{
+            /// The [remainder][std::ops::Rem] implementation for '&Vector % T'.
+            /// 
+            /// The remainder is taken of each element
+            /// divided by the scalar.
+            /// 
+            /// # Example
+            /// ```
+            /// use simp_linalg::vector_impl::Vector;
+            /// use simp_linalg::vector;
+            /// 
+            /// let vector = vector![4, 5, 6];
+            /// 
+            /// assert_eq!(&vector % 3, vector![1, 2, 0])
+            /// ```
+        },
+
+        //Vector<T> % T
+        (false, false) => quote!{
+            /// The [remainder][std::ops::Rem] implementation for 'Vector % T'.
+            /// 
+            /// The remainder is taken of each element
+            /// divided by the scalar.
+            /// 
+            /// # Example
+            /// ```
+            /// use simp_linalg::vector_impl::prelude::*;
+            /// 
+            /// let vector = vector![4, 5, 6];
+            /// 
+            /// // Notice that the vector is moved here.
+            /// assert_eq!(vector % 3, vector![1, 2, 0])
+            /// ```
+        },
+        _ => unreachable!()
+    }
+}
\ No newline at end of file
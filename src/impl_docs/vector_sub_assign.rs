@@ -0,0 +1,63 @@
+use quote::quote;
+
+pub fn vector_sub_assign_impl_doc(types_state : (bool, bool, bool, bool)) -> proc_macro2::TokenStream {
+    match types_state {
+        // Vector, &Vector
+        (false, false, true, false) => quote!{
+            /// The [subtraction assignment][std::ops::SubAssign] implementation for 'Vector -= &Vector'.
+            /// 
+            /// # Example
+            /// ```
+            /// use simp_linalg::vector_impl::prelude::*;
+            /// 
+            /// let mut vector1 = vector![5, 7, 9];
+            /// let vector2 = vector![1, 2, 3];
+            /// 
+            /// vector1 -= &vector2;
+            /// 
+            /// assert_eq!(vector1, vector![4, 5, 6])
+            /// ```
+            /// 
+            /// # Panic!
+            /// 
+            /// This function will panic if the vectors are not the same size.
+        },
+
+        // Vector, Vector
+        (false, false, false, false) => quote!{
+            /// The [subtraction assignment][std::ops::SubAssign] implementation for 'Vector -= Vector'.
+            /// 
+            /// # Example
+            /// ```
+            /// use simp_linalg::vector_impl::prelude::*;
+            /// 
+            /// let mut vector1 = vector![5, 7, 9];
+            /// let vector2 = vector![1, 2, 3];
+            /// 
+            /// // Notice that 'vector2' is moved here
+            /// vector1 -= vector2;
+            /// 
+            /// assert_eq!(vector1, vector![4, 5, 6])
+            /// ```
+            /// This is useful for subtracting vectors that are scaled.
+            /// ```
+            /// use simp_linalg::vector_impl::prelude::*;
+            /// 
+            /// let mut vector1 = vector![5, 7, 9];
+            /// let vector2 = vector![1, 2, 3];
+            /// 
+            /// // The result of '&vector2 * 2' is an owned Vector,
+            /// // which is then subtracted from 'vector1'.
+            /// vector1 -= &vector2 * 2;
+            /// 
+            /// assert_eq!(vector1, vector![3, 3, 3])
+            /// ```
+            /// 
+            /// # Panic!
+            /// 
+            /// This function will panic if the vectors are not the same size.
+        },
+
+        _ => unreachable!()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,113 @@
+use quote::quote;
+
+use crate::impl_docs::vector_elementwise::MethodExample;
+
+pub fn vector_try_binop_impl_doc(
+    trait_name : &str,
+    method : &str,
+    example : &MethodExample,
+    returns_vector : bool,
+    types_state : (bool, bool, bool, bool)
+) -> proc_macro2::TokenStream {
+    let (lhs_borrow, lhs_mut, rhs_borrow, rhs_mut) = types_state;
+
+    let (lhs_name, lhs_expr) = match (lhs_borrow, lhs_mut) {
+        (true, true) => ("&mut Vector", "(&mut vector1)"),
+        (true, false) => ("&Vector", "(&vector1)"),
+        (false, false) => ("Vector", "vector1"),
+        _ => unreachable!()
+    };
+
+    let (rhs_name, rhs_expr) = match (rhs_borrow, rhs_mut) {
+        (true, true) => ("&mut Vector", "&mut vector2"),
+        (true, false) => ("&Vector", "&vector2"),
+        (false, false) => ("Vector", "vector2"),
+        _ => unreachable!()
+    };
+
+    let summary = format!(
+        " The [{trait_name}] implementation for '{lhs_name}.{method}({rhs_name})'."
+    );
+
+    let lhs_decl = format!(
+        " let {}vector1 = vector![{}];",
+        if lhs_mut { "mut " } else { "" },
+        example.lhs
+    );
+    let rhs_decl = format!(
+        " let {}vector2 = vector![{}];",
+        if rhs_mut { "mut " } else { "" },
+        example.rhs
+    );
+
+    let moved = match (lhs_borrow, rhs_borrow) {
+        (false, false) => " // Notice that both vectors are moved here",
+        (false, true) => " // Notice that 'vector1' is moved here",
+        (true, false) => " // Notice that 'vector2' is moved here",
+        (true, true) => "",
+    };
+
+    let call = format!("{lhs_expr}.{method}({rhs_expr})");
+    let (operation, assertion) = if !returns_vector {
+        (format!(" let value = {call}.unwrap();"), format!(" assert_eq!(value, {})", example.result))
+    } else if lhs_mut {
+        (format!(" {call}.unwrap();"), format!(" assert_eq!(vector1, vector![{}])", example.result))
+    } else if rhs_mut {
+        (format!(" {call}.unwrap();"), format!(" assert_eq!(vector2, vector![{}])", example.result))
+    } else {
+        (format!(" let vector3 = {call}.unwrap();"), format!(" assert_eq!(vector3, vector![{}])", example.result))
+    };
+
+    let moved = if moved.is_empty() {
+        quote!()
+    } else {
+        quote!(#[doc = #moved])
+    };
+
+    let warning = if returns_vector && lhs_mut && rhs_mut {
+        quote!{
+            ///
+            /// # Warning
+            /// While the right hand side is mutable,
+            /// nothing will be mutated on the right hand side.
+            /// All changes will happen to the left hand side.
+        }
+    } else if !returns_vector && (lhs_mut || rhs_mut) {
+        quote!{
+            ///
+            /// # Warning
+            /// While the vectors are mutable,
+            /// nothing will be mutated in either vector.
+        }
+    } else {
+        quote!()
+    };
+
+    let errors = if returns_vector && (lhs_mut || rhs_mut) {
+        format!(" This function returns a `DimensionMismatch` if {}, in which case nothing is mutated.", example.panic)
+    } else {
+        format!(" This function returns a `DimensionMismatch` if {}.", example.panic)
+    };
+
+    quote!{
+        #[doc = #summary]
+        #warning
+        ///
+        /// # Example
+        /// ```
+        /// use simp_linalg::vector_impl::prelude::*;
+        ///
+        #[doc = #lhs_decl]
+        #[doc = #rhs_decl]
+        ///
+        #moved
+        #[doc = #operation]
+        ///
+        #[doc = #assertion]
+        /// ```
+        ///
+        /// # Errors
+        ///
+        #[doc = #errors]
+    }
+}
@@ -1,33 +1,96 @@
+//! Procedural macros generating the operators of vector and matrix types,
+//! such as `Add` for each combination of `&mut Vector<T>`, `&Vector<T>`
+//! and `Vector<T>` on either side.
+//!
+//! # Vector types
+//! The macros may name any vector type whose first generic argument is
+//! the element type and which keeps its elements in a `list` field,
+//! e.g. `vector_add_impl!(&Point<T> &Point<T>)`.
+//! The generic parameters are inferred from the types, unless they are
+//! given in front of them, e.g. `impl<T: Copy, U> &Tagged<T, U> &Tagged<T, U>`.
+//! Only single uppercase letters, such as `T` or `T2`, are inferred,
+//! so a concrete element type which is not a primitive is given with
+//! empty generics, e.g. `impl<> &Vector<Duration> &Vector<Duration>`.
+//!
+//! # Fixed-size vectors
+//! A vector type keeping its elements in a `[T; N]` is targeted with
+//! its const parameter, e.g. `impl<T, const N: usize> &Vector<T, N> &Vector<T, N>`,
+//! so the lengths are checked by the type system rather than at runtime.
+//!
+//! # `simd` feature
+//! The `+`, `-`, `*` and `/` operators, their assignments and the dot
+//! product of [vector_dot_prod_impl!] take an explicit AVX path when the
+//! element type is written as `f32` or `f64`, e.g. `&Samples<f32> &Samples<f32>`,
+//! and AVX is detected at runtime, falling back to a scalar loop otherwise.
+//! Generic element types, fixed-size vectors and mixed element types
+//! are left to the compiler.
+//!
+//! # `parallel` feature
+//! The elementwise and scalar operators and the dot product run with `rayon`
+//! for vectors of at least 65536 elements. This threshold can be changed with
+//! `parallel_threshold = ..` in [vector_ops_impl!] or the `#[vector_ops(..)]`
+//! attribute of the derive. The crate using the macros must then depend on
+//! `rayon`, and the element type is required to be `Send + Sync`.
+//! Fixed-size vectors and mixed element types always run sequentially.
+//!
+//! # Mixed element types
+//! The element types of the two sides may differ, e.g. `&Vector<T> &Vector<U>`,
+//! in which case the new vector holds the `Output` of the elements,
+//! such as `Vector<<T as Add<U>>::Output>`, and the dot product returns
+//! `<T as Mul<U>>::Output`. The `&mut` forms then require the `Output`
+//! to be the element type of the mutated side, and the elementwise
+//! assignments require e.g. `T: AddAssign<U>`.
+//!
+//! # Buffer reuse
+//! When an operand is owned, its elements are overwritten with the result
+//! and it is returned, so that e.g. `a + &b * 2.0 + &c` allocates one vector.
+//! The same holds for the owned vector of the scalar operators.
+//! With mixed element types, a new vector is built instead.
+
 extern crate proc_macro;
-use quote::quote;
-use syn::{Type, TypeReference, parse_macro_input};
-use syn::parse::Parse;
+use proc_macro2::{Group, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{BinOp, DeriveInput, GenericArgument, GenericParam, Generics, Ident, LitInt, PathArguments, Token, Type, TypeReference, parse_macro_input};
+use syn::parse::{Parse, Parser};
+use syn::punctuated::Punctuated;
 
 mod impl_docs;
+mod derive;
+mod expr;
+mod ops;
+mod parallel;
+mod simd;
 
-use crate::impl_docs::vector_add::vector_add_impl_doc;
-use crate::impl_docs::vector_dot_prod::vector_dot_prod_impl_doc;
-use crate::impl_docs::vector_scalar_mul::vector_scalar_mul_impl_doc;
+use crate::impl_docs::vector_binop::vector_binop_impl_doc;
+use crate::impl_docs::scalar_vector_mul::scalar_vector_mul_impl_doc;
+use crate::impl_docs::vector_neg::vector_neg_impl_doc;
+use crate::impl_docs::vector_expr::vector_expr_impl_doc;
+use crate::impl_docs::matrix_binop::{OperatorExample, matrix_binop_impl_doc};
+use crate::impl_docs::matrix_vector_mul::matrix_vector_mul_impl_doc;
 
 fn is_borrow(ty: &Type) -> bool {
-    match ty {
-        Type::Reference(_) => true,
-        _ => false
-    }
+    matches!(ty, Type::Reference(_))
 }
 
 fn is_mutable(ty: &Type) -> bool {
-    if let Type::Reference(TypeReference {
+    matches!(ty, Type::Reference(TypeReference {
         mutability : Some(_),
         ..
-    }) = ty {
-        true
+    }))
+}
+
+/// Parses the optional `impl<..>` generics in front of the types.
+fn parse_impl_generics(input: syn::parse::ParseStream) -> syn::Result<Option<Generics>> {
+    if input.peek(Token![impl]) {
+        input.parse::<Token![impl]>()?;
+        Ok(Some(input.parse()?))
     } else {
-        false
+        Ok(None)
     }
 }
 
 struct VectorImplTypes {
+    generics : Option<Generics>,
     lhs_ty : Box<Type>,
     rhs_ty : Box<Type>,
 }
@@ -35,354 +98,3620 @@ struct VectorImplTypes {
 impl Parse for VectorImplTypes {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         Ok(VectorImplTypes {
+            generics: parse_impl_generics(input)?,
             lhs_ty: input.parse()?,
             rhs_ty: input.parse()?
         })
     }
 }
 
-#[proc_macro]
-pub fn vector_add_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let data = parse_macro_input!(input as VectorImplTypes);
+struct VectorImplType {
+    generics : Option<Generics>,
+    ty : Box<Type>,
+}
 
-    let left_hand_type: proc_macro2::TokenStream;
-    let right_hand_type: proc_macro2::TokenStream;
+impl Parse for VectorImplType {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        Ok(VectorImplType {
+            generics: parse_impl_generics(input)?,
+            ty: input.parse()?
+        })
+    }
+}
 
-    let types_state = (
-        is_borrow(&data.lhs_ty), 
-        is_mutable(&data.lhs_ty), 
-        is_borrow(&data.rhs_ty), 
-        is_mutable(&data.rhs_ty)
-    );
+/// The input of `scalar_vector_mul_impl!`, the scalar types after
+/// an optional vector type, such as `f32, f64` or `Point<T>: f32, f64`.
+struct ScalarVectorMulImpl {
+    generics : Option<Generics>,
+    ty : Option<Box<Type>>,
+    types : Punctuated<Type, Token![,]>,
+}
 
-    match types_state {
-        // &mut Vector, &mut Vector
-        (true, true, true, true) => {
-            left_hand_type = quote!(&'a mut Vector<T>);
-            right_hand_type = quote!(&'a mut Vector<T>);
-        },
-        
-        // &mut Vector, &Vector
-        (true, true, true, false) => {
-            left_hand_type = quote!(&'a mut Vector<T>);
-            right_hand_type = quote!(&'a Vector<T>);
-        },
+impl Parse for ScalarVectorMulImpl {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let generics = parse_impl_generics(input)?;
 
-        // &mut Vector, Vector
-        (true, true, false, false) => {
-            left_hand_type = quote!(&'a mut Vector<T>);
-            right_hand_type = quote!(Vector<T>);
-        },
+        let fork = input.fork();
+        let ty = if generics.is_some()
+            || (fork.parse::<Type>().is_ok() && fork.peek(Token![:]) && !fork.peek(Token![::])) {
+            let ty = input.parse()?;
+            input.parse::<Token![:]>()?;
+            Some(ty)
+        } else {
+            None
+        };
 
-        // &Vector, &mut Vector
-        (true, false, true, true) => {
-            left_hand_type = quote!(&'a Vector<T>);
-            right_hand_type = quote!(&'a mut Vector<T>);
-        },
+        Ok(ScalarVectorMulImpl {
+            generics,
+            ty,
+            types: Punctuated::parse_terminated(input)?
+        })
+    }
+}
 
-        // Vector, &mut Vector
-        (false, false, true, true) => {
-            left_hand_type = quote!(Vector<T>);
-            right_hand_type = quote!(&'a mut Vector<T>);
-        },
+/// The input of `matrix_vector_mul_impl!`, the operands labelled with their role
+/// in the order of the product, such as `matrix: &Matrix<T>, vector: Vector<T>`
+/// or `vector: &Vector<T>, matrix: Matrix<T>`.
+struct MatrixVectorMulImpl {
+    types : VectorImplTypes,
+    matrix_on_left : bool,
+}
 
-        // -------------------------
+impl Parse for MatrixVectorMulImpl {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let generics = parse_impl_generics(input)?;
 
-        // &Vector, &Vector
-        (true, false, true, false) => {
-            left_hand_type = quote!(&Vector<T>);
-            right_hand_type = quote!(&Vector<T>);
-        },
+        let role = |input: syn::parse::ParseStream| -> syn::Result<Ident> {
+            let msg = "expected the operand labelled as `matrix: ..` or `vector: ..`";
+            let role = match input.parse::<Ident>() {
+                Ok(role) if role == "matrix" || role == "vector" => role,
+                Ok(other) => return Err(syn::Error::new_spanned(other, msg)),
+                Err(err) => return Err(syn::Error::new(err.span(), msg)),
+            };
+            input.parse::<Token![:]>()?;
+            Ok(role)
+        };
 
-        // &Vector, Vector
-        (true, false, false, false) => {
-            left_hand_type = quote!(&Vector<T>);
-            right_hand_type = quote!(Vector<T>);
-        },
+        let lhs_role = role(input)?;
+        let lhs_ty = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let rhs_role = role(input)?;
+        let rhs_ty = input.parse()?;
 
-        // Vector, &Vector
-        (false, false, true, false) => {
-            left_hand_type = quote!(Vector<T>);
-            right_hand_type = quote!(&Vector<T>);
-        },
+        if lhs_role == rhs_role {
+            return Err(syn::Error::new_spanned(
+                rhs_role,
+                "expected one `matrix` and one `vector`, e.g. `matrix: Matrix<T>, vector: Vector<T>`"
+            ))
+        }
 
-        // Vector, Vector
-        (false, false, false, false) => {
-            left_hand_type = quote!(Vector<T>);
-            right_hand_type = quote!(Vector<T>);
-        },
+        Ok(MatrixVectorMulImpl {
+            types: VectorImplTypes {
+                generics,
+                lhs_ty,
+                rhs_ty,
+            },
+            matrix_on_left: lhs_role == "matrix",
+        })
+    }
+}
 
-        _ => panic!("Not supported")
+impl VectorImplTypes {
+    fn types_state(&self) -> (bool, bool, bool, bool) {
+        (
+            is_borrow(&self.lhs_ty), 
+            is_mutable(&self.lhs_ty), 
+            is_borrow(&self.rhs_ty), 
+            is_mutable(&self.rhs_ty)
+        )
     }
+}
 
-    let documentation = vector_add_impl_doc(types_state);
+struct VectorBinOpImpl {
+    trait_name : Ident,
+    method : Ident,
+    operator : BinOp,
+    types : VectorImplTypes,
+}
 
-    let immut_impl = quote!{
-        #documentation
-        impl<T> Add<#right_hand_type> for #left_hand_type
-        where
-            T: Add<Output = T> + Copy
-        {
-            type Output = Vector<T>;
+impl Parse for VectorBinOpImpl {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let trait_name = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let method = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let operator = input.parse()?;
+        input.parse::<Token![;]>()?;
 
-            fn add(self, rhs: #right_hand_type) -> Self::Output {
-                if self.len() != rhs.len() {
-                    panic!("Vectors with different sizes cannot be added together.")
-                }
-                
-                let length = self.len();
+        Ok(VectorBinOpImpl {
+            trait_name,
+            method,
+            operator,
+            types: input.parse()?
+        })
+    }
+}
 
-                let mut params = Vec::with_capacity(length);
-                for idx in 0..length {
-                    params.push(self.list[idx] + rhs.list[idx])
-                }
+/// How the products of a dot product are summed.
+#[derive(Clone, Copy, PartialEq)]
+enum Summation {
+    /// Into separate lanes, which are added together at the end.
+    Naive,
+    /// With Kahan's compensated summation,
+    /// which carries the rounding error of each addition into the next.
+    Kahan,
+    /// As a balanced tree of partial sums over blocks of products.
+    Pairwise,
+}
 
-                Vector::from(params)
-            }
+impl Parse for Summation {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+
+        match ident.to_string().as_str() {
+            "naive" => Ok(Summation::Naive),
+            "kahan" => Ok(Summation::Kahan),
+            "pairwise" => Ok(Summation::Pairwise),
+            _ => Err(syn::Error::new_spanned(
+                ident,
+                "unknown summation, expected `naive`, `kahan` or `pairwise`"
+            ))
         }
-    };
+    }
+}
 
-    let left_mut_impl = quote!{
-        #documentation
-        impl<'a, T> Add<#right_hand_type> for #left_hand_type
-        where
-            T: Add<Output = T> + Copy
-        {
-            type Output = &'a mut Vector<T>;
+/// The input of the dot product macros, with an optional accumulator type
+/// and summation, such as `&Vector<T> &Vector<T> => i32` or
+/// `&Vector<f32> &Vector<f32>, summation = kahan`.
+struct DotProdImpl {
+    types : VectorImplTypes,
+    accumulator : Option<Type>,
+    summation : Summation,
+}
 
-            fn add(self, rhs: #right_hand_type) -> Self::Output {
-                if self.len() != rhs.len() {
-                    panic!("Vectors with different sizes cannot be added together.")
-                }
+impl Parse for DotProdImpl {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let types = input.parse()?;
 
-                for idx in 0..self.len() {
-                    self.list[idx] = self.list[idx] + rhs.list[idx]
-                }
+        let accumulator = if input.peek(Token![=>]) {
+            input.parse::<Token![=>]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
 
-                self
+        let summation = if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+
+            let key: Ident = input.parse()?;
+            if key != "summation" {
+                return Err(syn::Error::new_spanned(key, "expected `summation = ..`"));
             }
-        }
-    };
+            input.parse::<Token![=]>()?;
 
-    let right_mut_impl = quote!{
-        #documentation
-        impl<'a, T> Add<#right_hand_type> for #left_hand_type
-        where
-            T: Add<Output = T> + Copy
-        {
-            type Output = &'a mut Vector<T>;
+            input.parse()?
+        } else {
+            Summation::Naive
+        };
 
-            fn add(self, rhs: #right_hand_type) -> Self::Output {
-                if self.len() != rhs.len() {
-                    panic!("Vectors with different sizes cannot be added together.")
-                }
+        Ok(DotProdImpl {
+            types,
+            accumulator,
+            summation
+        })
+    }
+}
 
-                for idx in 0..self.len() {
-                    rhs.list[idx] = self.list[idx] + rhs.list[idx]
-                }
+/// The input of `vector_ops_impl!`, such as `Vector<T>: add, dot`,
+/// optionally followed by `parallel_threshold = ..`.
+struct VectorOpsImpl {
+    generics : Option<Generics>,
+    ty : Box<Type>,
+    ops : Vec<Ident>,
+    parallel_threshold : Option<usize>,
+}
 
-                rhs
+impl Parse for VectorOpsImpl {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let generics = parse_impl_generics(input)?;
+        let ty = input.parse()?;
+        input.parse::<Token![:]>()?;
+
+        let mut ops = Vec::new();
+        let mut parallel_threshold = None;
+
+        for item in Punctuated::<VectorOpsItem, Token![,]>::parse_terminated(input)? {
+            match item {
+                VectorOpsItem::Op(op) => ops.push(op),
+                VectorOpsItem::ParallelThreshold(threshold) => parallel_threshold = Some(threshold),
             }
         }
-    };
 
-    match (is_mutable(&data.lhs_ty), is_mutable(&data.rhs_ty)) {
-        (true, _) => {
-            return left_mut_impl.into();
-        },
-        (_, true) => {
-            return right_mut_impl.into();
-        },
-        (false, false) => {
-            return immut_impl.into();
-        },
+        Ok(VectorOpsImpl {
+            generics,
+            ty,
+            ops,
+            parallel_threshold
+        })
     }
 }
 
-#[proc_macro]
-pub fn vector_dot_prod_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let data = parse_macro_input!(input as VectorImplTypes);
+/// An item of the list of `vector_ops_impl!`.
+enum VectorOpsItem {
+    /// An operator, such as `add`.
+    Op(Ident),
+    /// The length from which the operators run in parallel, as in `parallel_threshold = 4096`.
+    ParallelThreshold(usize),
+}
 
-    let left_hand_type: proc_macro2::TokenStream;
-    let right_hand_type: proc_macro2::TokenStream;
+impl Parse for VectorOpsItem {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
 
-    let types_state = (
-        is_borrow(&data.lhs_ty), 
-        is_mutable(&data.lhs_ty), 
-        is_borrow(&data.rhs_ty), 
-        is_mutable(&data.rhs_ty)
-    );
+        if !input.peek(Token![=]) {
+            return Ok(VectorOpsItem::Op(ident))
+        }
 
-    match types_state {
-        // &mut Vector, &mut Vector
-        (true, true, true, true) => {
-            left_hand_type = quote!(&mut Vector<T>);
-            right_hand_type = quote!(&mut Vector<T>);
-        },
-        
-        // &mut Vector, &Vector
-        (true, true, true, false) => {
-            left_hand_type = quote!(&mut Vector<T>);
-            right_hand_type = quote!(&Vector<T>);
-        },
+        if ident != "parallel_threshold" {
+            return Err(syn::Error::new_spanned(ident, "expected an operator or `parallel_threshold = ..`"));
+        }
+        input.parse::<Token![=]>()?;
 
-        // &mut Vector, Vector
-        (true, true, false, false) => {
-            left_hand_type = quote!(&mut Vector<T>);
-            right_hand_type = quote!(Vector<T>);
-        },
+        Ok(VectorOpsItem::ParallelThreshold(input.parse::<LitInt>()?.base10_parse()?))
+    }
+}
 
-        // &Vector, &mut Vector
-        (true, false, true, true) => {
-            left_hand_type = quote!(&Vector<T>);
-            right_hand_type = quote!(&mut Vector<T>);
-        },
+/// The pieces of an elementwise operator that differ between
+/// the generated implementations.
+struct ElementwiseOp {
+    /// The trait being implemented for the vectors.
+    trait_name : proc_macro2::TokenStream,
+    /// The method of `trait_name`.
+    method : proc_macro2::TokenStream,
+    /// The trait the elements must implement.
+    element_trait : proc_macro2::TokenStream,
+    /// The operator applied to each pair of elements.
+    operator : proc_macro2::TokenStream,
+    /// The panic message used when the vector sizes differ.
+    mismatch_msg : String,
+}
 
-        // Vector, &mut Vector
-        (false, false, true, true) => {
-            left_hand_type = quote!(Vector<T>);
-            right_hand_type = quote!(&mut Vector<T>);
-        },
+impl ElementwiseOp {
+    fn add() -> Self {
+        ElementwiseOp {
+            trait_name: quote!(Add),
+            method: quote!(add),
+            element_trait: quote!(Add),
+            operator: quote!(+),
+            mismatch_msg: String::from("Vectors with different sizes cannot be added together.")
+        }
+    }
 
-        // -------------------------
+    fn sub() -> Self {
+        ElementwiseOp {
+            trait_name: quote!(Sub),
+            method: quote!(sub),
+            element_trait: quote!(Sub),
+            operator: quote!(-),
+            mismatch_msg: String::from("Vectors with different sizes cannot be subtracted from each other.")
+        }
+    }
 
-        // &Vector, &Vector
-        (true, false, true, false) => {
-            left_hand_type = quote!(&Vector<T>);
-            right_hand_type = quote!(&Vector<T>);
-        },
+    fn hadamard() -> Self {
+        ElementwiseOp {
+            trait_name: quote!(Hadamard),
+            method: quote!(hadamard),
+            element_trait: quote!(Mul),
+            operator: quote!(*),
+            mismatch_msg: String::from("Cannot find the Hadamard product of two differently sized vectors.")
+        }
+    }
 
-        // &Vector, Vector
-        (true, false, false, false) => {
-            left_hand_type = quote!(&Vector<T>);
-            right_hand_type = quote!(Vector<T>);
-        },
+    fn elem_div() -> Self {
+        ElementwiseOp {
+            trait_name: quote!(ElemDiv),
+            method: quote!(elem_div),
+            element_trait: quote!(Div),
+            operator: quote!(/),
+            mismatch_msg: String::from("Vectors with different sizes cannot be divided elementwise.")
+        }
+    }
 
-        // Vector, &Vector
-        (false, false, true, false) => {
-            left_hand_type = quote!(Vector<T>);
-            right_hand_type = quote!(&Vector<T>);
-        },
+    fn elem_rem() -> Self {
+        ElementwiseOp {
+            trait_name: quote!(ElemRem),
+            method: quote!(elem_rem),
+            element_trait: quote!(Rem),
+            operator: quote!(%),
+            mismatch_msg: String::from("Vectors with different sizes cannot be divided elementwise.")
+        }
+    }
 
-        // Vector, Vector
-        (false, false, false, false) => {
-            left_hand_type = quote!(Vector<T>);
-            right_hand_type = quote!(Vector<T>);
-        },
+    fn add_assign() -> Self {
+        ElementwiseOp {
+            trait_name: quote!(AddAssign),
+            method: quote!(add_assign),
+            element_trait: quote!(AddAssign),
+            operator: quote!(+=),
+            mismatch_msg: String::from("Vectors with different sizes cannot be added together.")
+        }
+    }
 
-        _ => panic!("Not supported")
+    fn sub_assign() -> Self {
+        ElementwiseOp {
+            trait_name: quote!(SubAssign),
+            method: quote!(sub_assign),
+            element_trait: quote!(SubAssign),
+            operator: quote!(-=),
+            mismatch_msg: String::from("Vectors with different sizes cannot be subtracted from each other.")
+        }
     }
+}
 
-    let documentation = vector_dot_prod_impl_doc(types_state);
+/// The vector type the operators are generated for.
+#[derive(Clone)]
+struct VectorTarget {
+    /// The owned vector type, such as `Vector<T>`.
+    ty : proc_macro2::TokenStream,
+    /// The generic parameters of the vector type, such as `T`.
+    params : proc_macro2::TokenStream,
+    /// The where clause predicates of the vector type.
+    predicates : Vec<proc_macro2::TokenStream>,
+    /// The type of the elements, such as `T`.
+    elem : proc_macro2::TokenStream,
+    /// The vector type on the right hand side of a binary operator,
+    /// which may differ in its element type, such as `Vector<U>`.
+    rhs_ty : proc_macro2::TokenStream,
+    /// The type of the elements on the right hand side, such as `U`.
+    rhs_elem : proc_macro2::TokenStream,
+    /// The field holding the elements, such as `list`.
+    field : proc_macro2::TokenStream,
+    /// Builds the vector from its elements named `params`,
+    /// a `Vec` or, for fixed-size vectors, an array.
+    construct : proc_macro2::TokenStream,
+    /// The length of a fixed-size vector backed by `[T; N]`, such as `N`.
+    length : Option<proc_macro2::TokenStream>,
+    /// The length from which the operators run in parallel
+    /// with the `parallel` feature.
+    parallel_threshold : usize,
+}
 
-    let implemtation = quote!{
-        #documentation
-        impl<T> Mul<#right_hand_type> for #left_hand_type
-        where
-            T: Copy + Mul<Output = T> + AddAssign + Default
-        {
-            type Output = T;
+/// A code path of a generated operator which returns early,
+/// such as the SIMD path of the `simd` feature.
+/// It is empty unless its feature is enabled.
+struct FastPath {
+    /// The where clause predicates needed by the path.
+    bound : proc_macro2::TokenStream,
+    /// The code of the path.
+    code : proc_macro2::TokenStream,
+}
 
-            fn mul(self, rhs: #right_hand_type) -> Self::Output {
-                if self.len() != rhs.len() {
-                    panic!("Cannot find dot product of two differently sized vectors.")
-                }
+impl FastPath {
+    fn none() -> Self {
+        FastPath {
+            bound: quote!(),
+            code: quote!(),
+        }
+    }
 
-                let mut product = T::default();
-                
-                for idx in 0..self.len() {
-                    product += self.list[idx] * rhs.list[idx]
-                }
+    /// Takes `self` before `other`.
+    fn or(self, other : FastPath) -> Self {
+        let (bound, other_bound) = (self.bound, other.bound);
+        let (code, other_code) = (self.code, other.code);
 
-                product
-            }
+        FastPath {
+            bound: quote!(#bound #other_bound),
+            code: quote!(#code #other_code),
         }
-    };
+    }
+}
 
-    implemtation.into()
+/// Where the result of an elementwise operator is written.
+#[derive(Clone, Copy)]
+enum Destination {
+    /// A new vector named `params`.
+    New,
+    /// The elements of `self`.
+    Lhs,
+    /// The elements of `rhs`.
+    Rhs,
 }
 
-#[proc_macro]
-pub fn vector_scalar_mul_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let data = parse_macro_input!(input as VectorImplTypes);
+impl Destination {
+    /// The lints allowed in an operator writing to the destination,
+    /// since writing in place as `*a = *a + *b` only requires the elements
+    /// to implement the operator rather than its assignment.
+    fn allowed_lints(self) -> proc_macro2::TokenStream {
+        match self {
+            Destination::New => quote!(),
+            _ => quote!(#[allow(clippy::assign_op_pattern)]),
+        }
+    }
+}
 
-    let left_hand_type: proc_macro2::TokenStream;
+/// The type names which are never inferred to be generic parameters.
+const PRIMITIVE_TYPES: [&str; 17] = [
+    "bool", "char", "str",
+    "i8", "i16", "i32", "i64", "i128", "isize",
+    "u8", "u16", "u32", "u64", "u128", "usize",
+    "f32", "f64",
+];
 
-    let type_state = (
-        is_borrow(&data.lhs_ty), 
-        is_mutable(&data.lhs_ty),
-    );
+/// Whether an identifier is named like a type parameter,
+/// a single uppercase letter optionally followed by digits, e.g. `T` or `T2`.
+fn is_type_param_name(name: &str) -> bool {
+    let mut chars = name.chars();
 
-    match type_state {
-        //&mut Vector<T> * T
-        (true, true) => {
-            left_hand_type = quote!(&'a mut Vector<T>)
-        },
+    matches!(chars.next(), Some(first) if first.is_ascii_uppercase())
+        && chars.all(|rest| rest.is_ascii_digit())
+}
 
-        //&Vector<T> * T
-        (true, false) => {
-            left_hand_type = quote!(&Vector<T>)
-        },
+/// The vector type with `elem` as its element type instead,
+/// e.g. `Vector<U>` for `Vector<T>`.
+fn with_elem(ty: &Type, elem: &Type) -> Type {
+    let mut ty = ty.clone();
 
-        //Vector<T> * T
-        (false, false) => {
-            left_hand_type = quote!(Vector<T>)
-        },
+    if let Type::Path(path) = &mut ty {
+        let first = path.path.segments.iter_mut()
+            .flat_map(|segment| match &mut segment.arguments {
+                PathArguments::AngleBracketed(args) => args.args.iter_mut().collect(),
+                _ => Vec::new()
+            })
+            .find(|arg| matches!(arg, GenericArgument::Type(_)));
 
-        _ => panic!("Not supported"),
+        if let Some(arg) = first {
+            *arg = GenericArgument::Type(elem.clone());
+        }
     }
 
-    let documentation = vector_scalar_mul_impl_doc(type_state);
+    ty
+}
 
-    let implementation;
+/// The generic parameters of both sides of a binary operator,
+/// with the lifetimes first, e.g. `T, U` for `Vector<T> Vector<U>`.
+fn merge_params(
+    lhs: &proc_macro2::TokenStream,
+    rhs: &proc_macro2::TokenStream
+) -> syn::Result<proc_macro2::TokenStream> {
+    let parser = Punctuated::<GenericParam, Token![,]>::parse_terminated;
 
-    if is_mutable(&data.lhs_ty) {
-        implementation = quote!{
-            #documentation
-            impl<'a, T> Mul<T> for #left_hand_type
-            where
-                T: Copy + Mul<Output = T>
-            {
-                type Output = &'a mut Vector<T>;
-                
-                fn mul(self, rhs: T) -> Self::Output {
-                    let list_ptr = self.list.as_mut_ptr();
-            
-                    for idx in 0..self.list.len() {
-                        //SAFETY:
-                        //Since the pointer to the list is
-                        //the same list which is being iterated
-                        //over, the index will never go out of scope.
-                        //
-                        //Additionally, the original values are used
-                        //before the memory location is overwitten.
-                        unsafe {
-                            self.list[idx] = rhs * *list_ptr.add(idx)
-                        }
-                    }
-                    self
-                }
-            }
-        };
-    } else {
-        implementation = quote!{
-            #documentation
-            impl<T> Mul<T> for #left_hand_type
-            where
-                T: Copy + Mul<Output = T>
-            {
-                type Output = Vector<T>;
-            
-                fn mul(self, rhs: T) -> Self::Output {
-                    let mut params = Vec::with_capacity(self.len());
-                    for item in self.list() {
-                        params.push(rhs * *item)
-                    }
-                    Vector::from(params)
-                }
-            }
-        };
+    let mut params: Vec<GenericParam> = parser.parse2(lhs.clone())?.into_iter().collect();
+    for param in parser.parse2(rhs.clone())? {
+        let name = param.to_token_stream().to_string();
+        if !params.iter().any(|known| known.to_token_stream().to_string() == name) {
+            params.push(param);
+        }
     }
+    params.sort_by_key(|param| !matches!(param, GenericParam::Lifetime(_)));
 
-    implementation.into()
+    Ok(quote!(#(#params),*))
+}
+
+/// The type behind any borrow.
+fn owned_type(ty: &Type) -> &Type {
+    match ty {
+        Type::Reference(reference) => owned_type(&reference.elem),
+        _ => ty
+    }
+}
+
+/// The tokens with every `ident` replaced by `replacement`,
+/// apart from lifetimes of the same name.
+fn replace_ident(
+    tokens: proc_macro2::TokenStream,
+    ident: &Ident,
+    replacement: &proc_macro2::TokenStream
+) -> proc_macro2::TokenStream {
+    let mut replaced = proc_macro2::TokenStream::new();
+    let mut lifetime = false;
+
+    for tree in tokens {
+        let after_quote = std::mem::replace(
+            &mut lifetime,
+            matches!(&tree, TokenTree::Punct(punct) if punct.as_char() == '\'')
+        );
+
+        match tree {
+            TokenTree::Ident(found) if found == *ident && !after_quote => replaced.extend(replacement.clone()),
+            TokenTree::Group(group) => {
+                let mut new = Group::new(group.delimiter(), replace_ident(group.stream(), ident, replacement));
+                new.set_span(group.span());
+                replaced.extend([TokenTree::Group(new)]);
+            },
+            other => replaced.extend([other]),
+        }
+    }
+
+    replaced
+}
+
+/// How an operand is passed to an operator.
+#[derive(Clone, Copy, PartialEq)]
+enum Ownership {
+    MutBorrow,
+    Borrow,
+    Owned,
+}
+
+use Ownership::{Borrow, MutBorrow, Owned};
+
+/// Every ownership supported for an operand of a binary operator.
+const ANY_OWNERSHIP: &[Ownership] = &[MutBorrow, Borrow, Owned];
+
+/// The ownerships of the vector being assigned to, e.g. `Vector<T> += &Vector<T>`.
+const ASSIGN_LHS: &[Ownership] = &[Owned];
+
+/// The ownerships of the right hand side of an elementwise assignment.
+const ASSIGN_RHS: &[Ownership] = &[Borrow, Owned];
+
+impl Ownership {
+    /// The ownership of the type, unless it is a borrow of a borrow.
+    fn of(ty: &Type) -> Option<Self> {
+        match ty {
+            Type::Reference(reference) if is_borrow(&reference.elem) => None,
+            _ if is_mutable(ty) => Some(MutBorrow),
+            _ if is_borrow(ty) => Some(Borrow),
+            _ => Some(Owned)
+        }
+    }
+
+    /// The owned type `ty` passed with this ownership.
+    fn apply(self, ty : &proc_macro2::TokenStream) -> Type {
+        match self {
+            MutBorrow => syn::parse_quote!(&mut #ty),
+            Borrow => syn::parse_quote!(&#ty),
+            Owned => syn::parse_quote!(#ty),
+        }
+    }
+
+    fn describe(self, owned : &str) -> String {
+        match self {
+            MutBorrow => format!("`&mut {owned}`"),
+            Borrow => format!("`&{owned}`"),
+            Owned => format!("`{owned}`"),
+        }
+    }
+}
+
+/// The type as written by hand, e.g. `Vector<T>` rather than
+/// the spaced out `Vector < T >` of its tokens.
+fn display_type(ty: &Type) -> String {
+    ty.to_token_stream().to_string()
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+}
+
+/// Records an error on `ty` if it is not passed with one of the `allowed` ownerships.
+fn check_ownership(ty: &Type, allowed: &[Ownership], operand: &str, errors: &mut Vec<syn::Error>) {
+    if matches!(Ownership::of(ty), Some(ownership) if allowed.contains(&ownership)) {
+        return;
+    }
+
+    let owned = display_type(owned_type(ty));
+    let supported: Vec<String> = allowed.iter().map(|ownership| ownership.describe(&owned)).collect();
+
+    let expected = match supported.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => unreachable!()
+    };
+
+    errors.push(syn::Error::new_spanned(
+        ty,
+        format!(
+            "`{}` is not supported as the {}, expected {}",
+            display_type(ty), operand, expected
+        )
+    ));
+}
+
+/// Records an error on `operator` unless it is an arithmetic or bitwise operator,
+/// the only ones that can be applied to each pair of elements.
+fn check_elementwise_operator(operator: &BinOp, errors: &mut Vec<syn::Error>) {
+    if matches!(
+        operator,
+        BinOp::Add(_) | BinOp::Sub(_) | BinOp::Mul(_) | BinOp::Div(_) | BinOp::Rem(_)
+            | BinOp::BitAnd(_) | BinOp::BitOr(_) | BinOp::BitXor(_) | BinOp::Shl(_) | BinOp::Shr(_)
+    ) {
+        return;
+    }
+
+    errors.push(syn::Error::new_spanned(
+        operator,
+        format!(
+            "`{}` is not supported as the operator, expected one of \
+             `+`, `-`, `*`, `/`, `%`, `&`, `|`, `^`, `<<` or `>>`",
+            operator.to_token_stream()
+        )
+    ));
+}
+
+/// Combines the errors into one, so they are all reported in the same expansion.
+fn combine_errors(errors: Vec<syn::Error>) -> syn::Result<()> {
+    match errors.into_iter().reduce(|mut combined, err| {
+        combined.combine(err);
+        combined
+    }) {
+        Some(err) => Err(err),
+        None => Ok(())
+    }
+}
+
+/// The documentation, if the type is the upstream `Vector<T>`
+/// which the examples are written for.
+fn vector_documentation(ty: &Type, documentation: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    upstream_documentation(ty, "Vector", documentation)
+}
+
+/// The documentation, if the type is the upstream `Matrix<T>`
+/// which the examples are written for.
+fn matrix_documentation(ty: &Type, documentation: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    upstream_documentation(ty, "Matrix", documentation)
+}
+
+/// The documentation, if the type is named `name` with a single generic argument.
+fn upstream_documentation(ty: &Type, name: &str, documentation: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let segment = match owned_type(ty) {
+        Type::Path(path) => path.path.segments.last(),
+        _ => None
+    };
+
+    match segment {
+        Some(segment) if segment.ident == name => match &segment.arguments {
+            PathArguments::AngleBracketed(args) if args.args.len() == 1 => documentation,
+            _ => quote!()
+        },
+        _ => quote!()
+    }
+}
+
+impl VectorTarget {
+    /// The vector type named in the macro input, such as `Vector<T>`.
+    /// 
+    /// The first generic argument of the type is its element type,
+    /// and the elements are expected in the field `list`.
+    /// 
+    /// Unless the generics are given as `impl<..>` in front of the types,
+    /// every lifetime and every bare identifier in the generic arguments
+    /// named like a type parameter, such as `T` or `T2`, is a generic parameter.
+    /// Any other bare identifier which is not a primitive type is rejected,
+    /// as it may name a concrete type, such as `Duration`,
+    /// which is then given with `impl<>` instead.
+    /// 
+    /// The vector has a fixed size if a later generic argument is
+    /// a const parameter of the given generics or a literal,
+    /// e.g. `impl<T, const N: usize> Vector<T, N>` or `Vector<f32, 3>`.
+    fn from_input(generics: &Option<Generics>, ty: &Type) -> syn::Result<Self> {
+        let ty = owned_type(ty);
+
+        let path = match ty {
+            Type::Path(path) if path.qself.is_none() => &path.path,
+            _ => return Err(syn::Error::new_spanned(
+                ty,
+                "expected a vector type with its element type as the first generic argument, e.g. `Vector<T>`"
+            ))
+        };
+
+        let args: Vec<&GenericArgument> = path.segments.iter()
+            .flat_map(|segment| match &segment.arguments {
+                PathArguments::AngleBracketed(args) => args.args.iter().collect(),
+                _ => Vec::new()
+            })
+            .collect();
+
+        let elem = match args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(elem) => Some(elem),
+            _ => None
+        }) {
+            Some(elem) => elem.to_token_stream(),
+            None => return Err(syn::Error::new_spanned(
+                ty,
+                format!(
+                    "expected the element type as the first generic argument, e.g. `{}<T>`",
+                    display_type(ty)
+                )
+            ))
+        };
+
+        let const_params: Vec<&Ident> = generics.iter()
+            .flat_map(|generics| generics.const_params().map(|param| &param.ident))
+            .collect();
+
+        let length = args.iter().skip(1).find_map(|arg| match arg {
+            GenericArgument::Const(length) => Some(length.to_token_stream()),
+            GenericArgument::Type(Type::Path(arg)) => arg.path.get_ident()
+                .filter(|ident| const_params.contains(ident))
+                .map(ToTokens::to_token_stream),
+            _ => None
+        });
+
+        let (params, predicates) = match generics {
+            Some(generics) => (
+                generics.params.to_token_stream(),
+                generics.where_clause.iter()
+                    .flat_map(|clause| clause.predicates.iter().map(ToTokens::to_token_stream))
+                    .collect()
+            ),
+            None => {
+                let mut lifetimes = Vec::new();
+                let mut types: Vec<&Ident> = Vec::new();
+
+                for arg in args.iter() {
+                    match arg {
+                        GenericArgument::Lifetime(lifetime) => lifetimes.push(lifetime),
+                        GenericArgument::Type(Type::Path(arg)) if arg.qself.is_none() => {
+                            if let Some(ident) = arg.path.get_ident() {
+                                let name = ident.to_string();
+                                if PRIMITIVE_TYPES.contains(&name.as_str()) || types.contains(&ident) {
+                                    continue
+                                }
+
+                                if !is_type_param_name(&name) {
+                                    return Err(syn::Error::new_spanned(
+                                        ident,
+                                        format!(
+                                            "cannot infer whether `{}` is a generic parameter, \
+                                             declare the generics in front of the types, \
+                                             e.g. `impl<> {}` if it is a concrete type",
+                                            name, display_type(ty)
+                                        )
+                                    ))
+                                }
+
+                                types.push(ident)
+                            }
+                        },
+                        _ => ()
+                    }
+                }
+
+                (quote!(#(#lifetimes,)* #(#types),*), Vec::new())
+            }
+        };
+
+        Ok(VectorTarget {
+            ty: ty.to_token_stream(),
+            params,
+            predicates,
+            rhs_ty: ty.to_token_stream(),
+            rhs_elem: elem.clone(),
+            elem,
+            field: quote!(list),
+            construct: quote!(<#ty>::from(params)),
+            length,
+            parallel_threshold: parallel::DEFAULT_THRESHOLD,
+        })
+    }
+
+    /// The lifetime of the borrowed operands, `'a` unless the generic
+    /// parameters already name it, in which case a number is appended
+    /// until the name is free, such as `'a1`.
+    fn borrow_lifetime(&self) -> syn::Lifetime {
+        fn lifetimes(tokens: proc_macro2::TokenStream, names: &mut Vec<String>) {
+            let mut tokens = tokens.into_iter().peekable();
+            while let Some(token) = tokens.next() {
+                match token {
+                    TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                        if let Some(TokenTree::Ident(ident)) = tokens.peek() {
+                            names.push(ident.to_string());
+                        }
+                    },
+                    TokenTree::Group(group) => lifetimes(group.stream(), names),
+                    _ => {}
+                }
+            }
+        }
+
+        let mut names = Vec::new();
+        lifetimes(self.params.clone(), &mut names);
+
+        let name = (0..)
+            .map(|idx| if idx == 0 { String::from("a") } else { format!("a{}", idx) })
+            .find(|name| !names.contains(name))
+            .unwrap();
+
+        syn::Lifetime::new(&format!("'{}", name), proc_macro2::Span::call_site())
+    }
+
+    /// Runs `mismatch` if `self` and `rhs` are not the same size.
+    /// 
+    /// Nothing is checked for fixed-size vectors,
+    /// as differently sized vectors are different types.
+    fn length_check(&self, mismatch: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let field = &self.field;
+
+        match self.length {
+            Some(_) => quote!(),
+            None => quote!{
+                if self.#field.len() != rhs.#field.len() {
+                    #mismatch
+                }
+            }
+        }
+    }
+
+    /// Builds a vector from the result of the `element` expression
+    /// for each element `a` of `self`.
+    fn map(&self, element: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        self.map_operand(quote!(self), element)
+    }
+
+    /// Builds a vector from the result of the `element` expression
+    /// for each element `a` of the `operand`, such as `rhs`.
+    fn map_operand(&self, operand: proc_macro2::TokenStream, element: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let VectorTarget {
+            elem,
+            field,
+            construct,
+            length,
+            ..
+        } = self;
+
+        match length {
+            Some(length) => quote!{
+                let params: [#elem; #length] = ::core::array::from_fn(|idx| {
+                    let a = &#operand.#field[idx];
+                    #element
+                });
+
+                #construct
+            },
+            None => quote!{
+                let params: Vec<#elem> = #operand.#field.iter()
+                    .map(|a| #element)
+                    .collect();
+
+                #construct
+            }
+        }
+    }
+
+    /// Builds a vector from the result of the `element` expression
+    /// for each pair of elements `a` of `self` and `b` of `rhs`,
+    /// which must be the same size.
+    /// 
+    /// The zipped iterators have an exact size, so the elements are
+    /// collected without bounds checks or reallocation.
+    fn zip_map(&self, element: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let VectorTarget {
+            elem,
+            field,
+            construct,
+            length,
+            ..
+        } = self;
+
+        match length {
+            Some(length) => quote!{
+                let params: [#elem; #length] = ::core::array::from_fn(|idx| {
+                    let (a, b) = (&self.#field[idx], &rhs.#field[idx]);
+                    #element
+                });
+
+                #construct
+            },
+            None => quote!{
+                let params: Vec<#elem> = self.#field.iter()
+                    .zip(rhs.#field.iter())
+                    .map(|(a, b)| #element)
+                    .collect();
+
+                #construct
+            }
+        }
+    }
+
+    /// Sums the products of the elements of `self` and `rhs`,
+    /// which must be the same size, into `product`.
+    /// 
+    /// With the naive summation, the products are accumulated in
+    /// separate lanes which are only added together at the end,
+    /// so the loop can be vectorized without reordering
+    /// the additions within any lane.
+    /// 
+    /// With an `accumulator` type, both elements of each product
+    /// are converted into it with `From` before being multiplied.
+    fn dot_product(
+        &self,
+        accumulator: Option<&proc_macro2::TokenStream>,
+        summation: Summation
+    ) -> proc_macro2::TokenStream {
+        let VectorTarget {
+            elem,
+            field,
+            ..
+        } = self;
+
+        let (elem, term) = match accumulator {
+            Some(accumulator) => (
+                accumulator,
+                quote!(<#accumulator>::from(*a) * <#accumulator>::from(*b))
+            ),
+            None => (elem, quote!(*a * *b)),
+        };
+
+        match summation {
+            Summation::Naive => quote!{
+                const LANES: usize = 8;
+
+                let lhs_chunks = self.#field.chunks_exact(LANES);
+                let rhs_chunks = rhs.#field.chunks_exact(LANES);
+                let lhs_rest = lhs_chunks.remainder();
+                let rhs_rest = rhs_chunks.remainder();
+
+                let mut lanes = [<#elem>::default(); LANES];
+                for (lhs_chunk, rhs_chunk) in lhs_chunks.zip(rhs_chunks) {
+                    for (lane, (a, b)) in lanes.iter_mut().zip(lhs_chunk.iter().zip(rhs_chunk)) {
+                        *lane += #term
+                    }
+                }
+
+                let mut product = <#elem>::default();
+                for lane in lanes.iter() {
+                    product += *lane
+                }
+                for (a, b) in lhs_rest.iter().zip(rhs_rest) {
+                    product += #term
+                }
+            },
+            Summation::Kahan => quote!{
+                let mut product = <#elem>::default();
+                let mut compensation = <#elem>::default();
+
+                for (a, b) in self.#field.iter().zip(rhs.#field.iter()) {
+                    let term = #term - compensation;
+                    let sum = product + term;
+                    compensation = (sum - product) - term;
+                    product = sum;
+                }
+            },
+            // Each full block is summed on its own, and two partial sums of
+            // the same number of blocks are added together as soon as both
+            // exist, which keeps at most one per bit of the number of blocks.
+            Summation::Pairwise => quote!{
+                const BLOCK: usize = 8;
+
+                let lhs_chunks = self.#field.chunks_exact(BLOCK);
+                let rhs_chunks = rhs.#field.chunks_exact(BLOCK);
+                let lhs_rest = lhs_chunks.remainder();
+                let rhs_rest = rhs_chunks.remainder();
+
+                let mut partials = [<#elem>::default(); usize::BITS as usize];
+                let mut depth = 0;
+                for (blocks, (lhs_chunk, rhs_chunk)) in lhs_chunks.zip(rhs_chunks).enumerate() {
+                    let mut sum = <#elem>::default();
+                    for (a, b) in lhs_chunk.iter().zip(rhs_chunk) {
+                        sum += #term
+                    }
+
+                    for _ in 0..(blocks + 1).trailing_zeros() {
+                        depth -= 1;
+                        sum = partials[depth] + sum;
+                    }
+                    partials[depth] = sum;
+                    depth += 1;
+                }
+
+                let mut product = <#elem>::default();
+                for (a, b) in lhs_rest.iter().zip(rhs_rest) {
+                    product += #term
+                }
+                for partial in partials[..depth].iter().rev() {
+                    product = *partial + product;
+                }
+            },
+        }
+    }
+
+    /// The target of a macro taking a single vector type,
+    /// which must be passed with one of the `allowed` ownerships.
+    fn from_unary_input(data: &VectorImplType, allowed: &[Ownership]) -> syn::Result<Self> {
+        let mut errors = Vec::new();
+
+        check_ownership(&data.ty, allowed, "operand", &mut errors);
+
+        let target = VectorTarget::from_input(&data.generics, &data.ty);
+        if let Err(err) = &target {
+            errors.push(err.clone())
+        }
+
+        combine_errors(errors)?;
+        target
+    }
+
+    /// The target of a macro taking two vector types,
+    /// which must name the same type apart from their element types,
+    /// e.g. `Vector<T> Vector<U>`, and be passed with
+    /// one of the `lhs` and `rhs` ownerships respectively.
+    fn from_binop_input(data: &VectorImplTypes, lhs: &[Ownership], rhs: &[Ownership]) -> syn::Result<Self> {
+        let mut errors = Vec::new();
+
+        check_ownership(&data.lhs_ty, lhs, "left hand side", &mut errors);
+        check_ownership(&data.rhs_ty, rhs, "right hand side", &mut errors);
+
+        let lhs_owned = owned_type(&data.lhs_ty);
+        let rhs_owned = owned_type(&data.rhs_ty);
+
+        let mut target = VectorTarget::from_input(&data.generics, &data.lhs_ty);
+        match (&mut target, VectorTarget::from_input(&data.generics, &data.rhs_ty)) {
+            (Ok(target), Ok(rhs_target)) => {
+                let rhs_elem = &rhs_target.elem;
+
+                let expected = with_elem(lhs_owned, &syn::parse_quote!(#rhs_elem));
+
+                if expected.to_token_stream().to_string() == rhs_owned.to_token_stream().to_string() {
+                    target.params = merge_params(&target.params, &rhs_target.params)?;
+                    target.rhs_ty = rhs_target.ty;
+                    target.rhs_elem = rhs_target.elem;
+                } else {
+                    errors.push(syn::Error::new_spanned(
+                        rhs_owned,
+                        format!(
+                            "expected the same vector type on both sides apart from the element type, found `{}` and `{}`",
+                            display_type(lhs_owned), display_type(rhs_owned)
+                        )
+                    ));
+                }
+            },
+            (Ok(_), Err(err)) => errors.push(err),
+            (Err(err), _) => errors.push(err.clone()),
+        }
+
+        combine_errors(errors)?;
+        target
+    }
+
+    /// Whether the elements on the right hand side of
+    /// a binary operator are of another type than `elem`.
+    fn is_mixed(&self) -> bool {
+        self.elem.to_string() != self.rhs_elem.to_string()
+    }
+
+    /// The where clause predicates of an elementwise operator, requiring
+    /// the elements to implement its `element_trait` with the given `Output`,
+    /// e.g. `T: Add<Output = T> + Copy,` or `T: Add<U> + Copy, U: Copy,`
+    /// if the elements on the right hand side are of another type `U`.
+    fn element_bounds(
+        &self,
+        element_trait: &proc_macro2::TokenStream,
+        output: Option<&proc_macro2::TokenStream>
+    ) -> proc_macro2::TokenStream {
+        let VectorTarget {
+            elem,
+            rhs_elem,
+            ..
+        } = self;
+
+        let mut args = Vec::new();
+        if self.is_mixed() {
+            args.push(quote!(#rhs_elem));
+        }
+        if let Some(output) = output {
+            args.push(quote!(Output = #output));
+        }
+
+        let element_trait = if args.is_empty() {
+            quote!(#element_trait)
+        } else {
+            quote!(#element_trait<#(#args),*>)
+        };
+
+        if self.is_mixed() {
+            quote!(#elem: #element_trait + Copy, #rhs_elem: Copy,)
+        } else {
+            quote!(#elem: #element_trait + Copy,)
+        }
+    }
+
+    /// The target of the vector built by a binary operator, whose elements
+    /// are the `Output` of the `element_trait` of the elements on both sides,
+    /// e.g. `Vector<<T as Add<U>>::Output>` for `Vector<T> + Vector<U>`.
+    fn binop_output(&self, element_trait: &proc_macro2::TokenStream) -> Self {
+        if !self.is_mixed() {
+            return self.clone();
+        }
+
+        let VectorTarget {
+            ty,
+            elem,
+            rhs_elem,
+            ..
+        } = self;
+
+        let elem = quote!(<#elem as #element_trait<#rhs_elem>>::Output);
+        let ty = with_elem(&syn::parse_quote!(#ty), &syn::parse_quote!(#elem));
+
+        VectorTarget {
+            ty: ty.to_token_stream(),
+            construct: quote!(<#ty>::from(params)),
+            elem,
+            ..self.clone()
+        }
+    }
+
+    /// The target with its generic element type replaced by the concrete `elem`,
+    /// such as `Vector<f32>` for `impl<T> Vector<T>`,
+    /// or `None` if the element type is not a generic parameter.
+    fn instantiate(&self, elem: &Type) -> syn::Result<Option<Self>> {
+        let param: Ident = match syn::parse2(self.elem.clone()) {
+            Ok(param) => param,
+            Err(_) => return Ok(None)
+        };
+
+        let parser = Punctuated::<GenericParam, Token![,]>::parse_terminated;
+        let params = parser.parse2(self.params.clone())?;
+
+        let is_elem = |generic: &GenericParam| matches!(generic, GenericParam::Type(ty) if ty.ident == param);
+        if !params.iter().any(is_elem) {
+            return Ok(None)
+        }
+
+        let elem = elem.to_token_stream();
+        let replace = |tokens: &proc_macro2::TokenStream| replace_ident(tokens.clone(), &param, &elem);
+        let params: Vec<_> = params.iter()
+            .filter(|generic| !is_elem(generic))
+            .map(|generic| replace(&generic.to_token_stream()))
+            .collect();
+
+        Ok(Some(VectorTarget {
+            ty: replace(&self.ty),
+            params: quote!(#(#params),*),
+            predicates: self.predicates.iter().map(replace).collect(),
+            elem: elem.clone(),
+            rhs_ty: replace(&self.rhs_ty),
+            rhs_elem: replace(&self.rhs_elem),
+            field: self.field.clone(),
+            construct: replace(&self.construct),
+            length: self.length.as_ref().map(replace),
+            parallel_threshold: self.parallel_threshold,
+        }))
+    }
+
+    /// The target of a macro taking a vector type and a scalar,
+    /// where the vector must be passed with one of the `allowed` ownerships
+    /// and the scalar must be its owned element type.
+    fn from_scalar_input(data: &VectorImplTypes, allowed: &[Ownership]) -> syn::Result<Self> {
+        let mut errors = Vec::new();
+
+        check_ownership(&data.lhs_ty, allowed, "vector", &mut errors);
+
+        let target = VectorTarget::from_input(&data.generics, &data.lhs_ty);
+        match &target {
+            Ok(target) => {
+                let elem = &target.elem;
+                if data.rhs_ty.to_token_stream().to_string() != elem.to_string() {
+                    errors.push(syn::Error::new_spanned(
+                        &data.rhs_ty,
+                        format!(
+                            "`{}` is not supported as the scalar, expected the element type `{}` taken by value",
+                            display_type(&data.rhs_ty), display_type(&syn::parse_quote!(#elem))
+                        )
+                    ));
+                }
+            },
+            Err(err) => errors.push(err.clone())
+        }
+
+        combine_errors(errors)?;
+        target
+    }
+}
+
+/// The left and right hand types of a binary operator between
+/// values of the `lhs_target` and `rhs_target` types, such as `Vector<T>`,
+/// where the mutable borrows share the `lifetime`.
+fn binop_operand_types(
+    data : &VectorImplTypes,
+    lifetime : &syn::Lifetime,
+    lhs_target : &proc_macro2::TokenStream,
+    rhs_target : &proc_macro2::TokenStream
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let left_hand_type: proc_macro2::TokenStream;
+    let right_hand_type: proc_macro2::TokenStream;
+
+    match data.types_state() {
+        // &mut Vector, &mut Vector
+        (true, true, true, true) => {
+            left_hand_type = quote!(&#lifetime mut #lhs_target);
+            right_hand_type = quote!(&#lifetime mut #rhs_target);
+        },
+        
+        // &mut Vector, &Vector
+        (true, true, true, false) => {
+            left_hand_type = quote!(&#lifetime mut #lhs_target);
+            right_hand_type = quote!(&#lifetime #rhs_target);
+        },
+
+        // &mut Vector, Vector
+        (true, true, false, false) => {
+            left_hand_type = quote!(&#lifetime mut #lhs_target);
+            right_hand_type = quote!(#rhs_target);
+        },
+
+        // &Vector, &mut Vector
+        (true, false, true, true) => {
+            left_hand_type = quote!(&#lifetime #lhs_target);
+            right_hand_type = quote!(&#lifetime mut #rhs_target);
+        },
+
+        // Vector, &mut Vector
+        (false, false, true, true) => {
+            left_hand_type = quote!(#lhs_target);
+            right_hand_type = quote!(&#lifetime mut #rhs_target);
+        },
+
+        // -------------------------
+
+        // &Vector, &Vector
+        (true, false, true, false) => {
+            left_hand_type = quote!(&#lhs_target);
+            right_hand_type = quote!(&#rhs_target);
+        },
+
+        // &Vector, Vector
+        (true, false, false, false) => {
+            left_hand_type = quote!(&#lhs_target);
+            right_hand_type = quote!(#rhs_target);
+        },
+
+        // Vector, &Vector
+        (false, false, true, false) => {
+            left_hand_type = quote!(#lhs_target);
+            right_hand_type = quote!(&#rhs_target);
+        },
+
+        // Vector, Vector
+        (false, false, false, false) => {
+            left_hand_type = quote!(#lhs_target);
+            right_hand_type = quote!(#rhs_target);
+        },
+
+        _ => unreachable!()
+    }
+
+    (left_hand_type, right_hand_type)
+}
+
+/// Where a binary operator between vectors writes its result.
+///
+/// A mutably borrowed operand is always written, and otherwise an owned operand
+/// is reused instead of allocating a new vector, unless the elements differ in type.
+fn binop_destination(target : &VectorTarget, data : &VectorImplTypes) -> Destination {
+    match (is_mutable(&data.lhs_ty), is_mutable(&data.rhs_ty)) {
+        (true, _) => Destination::Lhs,
+        (_, true) => Destination::Rhs,
+        _ if target.is_mixed() => Destination::New,
+        _ if !is_borrow(&data.lhs_ty) => Destination::Lhs,
+        _ if !is_borrow(&data.rhs_ty) => Destination::Rhs,
+        _ => Destination::New,
+    }
+}
+
+/// The fast path and body of an elementwise operator writing to `destination`,
+/// where `wrap` turns the written vector into the returned value.
+fn elementwise_binop_body(
+    operator : &proc_macro2::TokenStream,
+    target : &VectorTarget,
+    output : &VectorTarget,
+    destination : Destination,
+    wrap : impl Fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream
+) -> (FastPath, proc_macro2::TokenStream) {
+    let VectorTarget {
+        field,
+        construct,
+        ..
+    } = target;
+
+    match destination {
+        Destination::Lhs => {
+            let result = wrap(quote!(self));
+            let fast_path = parallel::zip(target, quote!(*a = *a #operator *b), destination, result.clone())
+                .or(simd::elementwise(target, operator, destination, result.clone()));
+
+            (fast_path, quote!{
+                for (a, b) in self.#field.iter_mut().zip(rhs.#field.iter()) {
+                    *a = *a #operator *b
+                }
+
+                #result
+            })
+        },
+        Destination::Rhs => {
+            let result = wrap(quote!(rhs));
+            let fast_path = parallel::zip(target, quote!(*b = *a #operator *b), destination, result.clone())
+                .or(simd::elementwise(target, operator, destination, result.clone()));
+
+            (fast_path, quote!{
+                for (a, b) in self.#field.iter().zip(rhs.#field.iter_mut()) {
+                    *b = *a #operator *b
+                }
+
+                #result
+            })
+        },
+        Destination::New => {
+            let build = output.zip_map(quote!(*a #operator *b));
+            let fast_path = parallel::zip(target, quote!(*a #operator *b), destination, wrap(output.construct.clone()))
+                .or(simd::elementwise(target, operator, destination, wrap(construct.clone())));
+
+            (fast_path, wrap(quote!({ #build })))
+        },
+    }
+}
+
+/// The bindings of `self` and `rhs` in an elementwise operator
+/// writing to `destination`, where an owned destination is bound mutably.
+fn binop_bindings(data : &VectorImplTypes, destination : Destination) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    match destination {
+        Destination::Lhs if !is_borrow(&data.lhs_ty) => (quote!(mut self), quote!(rhs)),
+        Destination::Rhs if !is_borrow(&data.rhs_ty) => (quote!(self), quote!(mut rhs)),
+        _ => (quote!(self), quote!(rhs)),
+    }
+}
+
+fn elementwise_binop_impl(
+    op : &ElementwiseOp,
+    target : &VectorTarget,
+    data : &VectorImplTypes,
+    documentation : proc_macro2::TokenStream
+) -> proc_macro2::TokenStream {
+    let ElementwiseOp {
+        trait_name,
+        method,
+        element_trait,
+        operator,
+        mismatch_msg
+    } = op;
+
+    let VectorTarget {
+        ty,
+        params,
+        predicates,
+        elem,
+        rhs_ty,
+        rhs_elem,
+        ..
+    } = target;
+
+    let lifetime = target.borrow_lifetime();
+    let (left_hand_type, right_hand_type) = binop_operand_types(data, &lifetime, ty, rhs_ty);
+
+    let output_target = target.binop_output(element_trait);
+    let destination = binop_destination(target, data);
+
+    let length_check = target.length_check(quote!(panic!(#mismatch_msg)));
+    let (fast_path, body) = elementwise_binop_body(operator, target, &output_target, destination, |result| result);
+    let FastPath { bound, code: fast_path } = fast_path;
+
+    let (lhs_binding, rhs_binding) = binop_bindings(data, destination);
+    let allowed_lints = destination.allowed_lints();
+    let lifetime_param = (is_mutable(&data.lhs_ty) || is_mutable(&data.rhs_ty)).then(|| quote!(#lifetime,));
+
+    let (output, element_output) = match destination {
+        Destination::Lhs => (left_hand_type.clone(), Some(elem)),
+        Destination::Rhs => (right_hand_type.clone(), Some(rhs_elem)),
+        Destination::New => (output_target.ty.clone(), (!target.is_mixed()).then_some(elem)),
+    };
+
+    let element_bounds = target.element_bounds(element_trait, element_output);
+
+    quote!{
+        #documentation
+        impl<#lifetime_param #params> #trait_name<#right_hand_type> for #left_hand_type
+        where
+            #element_bounds
+            #bound
+            #(#predicates,)*
+        {
+            type Output = #output;
+
+            #allowed_lints
+            fn #method(#lhs_binding, #rhs_binding: #right_hand_type) -> Self::Output {
+                #length_check
+
+                #fast_path
+
+                #body
+            }
+        }
+    }
+}
+
+/// The fallible counterpart of [elementwise_binop_impl], implementing
+/// e.g. `TryAdd::try_add` for the `Add::add` of `op`, which returns
+/// a `DimensionMismatch` instead of panicking.
+fn try_elementwise_binop_impl(
+    op : &ElementwiseOp,
+    target : &VectorTarget,
+    data : &VectorImplTypes,
+    documentation : proc_macro2::TokenStream
+) -> proc_macro2::TokenStream {
+    let ElementwiseOp {
+        trait_name,
+        method,
+        element_trait,
+        operator,
+        ..
+    } = op;
+
+    let VectorTarget {
+        ty,
+        params,
+        predicates,
+        elem,
+        field,
+        rhs_ty,
+        rhs_elem,
+        ..
+    } = target;
+
+    let try_trait = format_ident!("Try{}", trait_name.to_string());
+    let try_method = format_ident!("try_{}", method.to_string());
+    let try_op = match method.to_string().as_str() {
+        "add" => quote!(TryOp::Add),
+        "sub" => quote!(TryOp::Sub),
+        _ => unreachable!()
+    };
+
+    let lifetime = target.borrow_lifetime();
+    let (left_hand_type, right_hand_type) = binop_operand_types(data, &lifetime, ty, rhs_ty);
+
+    let output_target = target.binop_output(element_trait);
+    let destination = binop_destination(target, data);
+
+    let length_check = target.length_check(quote!{
+        return Err(DimensionMismatch {
+            op: #try_op,
+            lhs_len: self.#field.len(),
+            rhs_len: rhs.#field.len(),
+        })
+    });
+    let (fast_path, result) = elementwise_binop_body(operator, target, &output_target, destination, |result| quote!(Ok(#result)));
+    let FastPath { bound, code: fast_path } = fast_path;
+
+    let (lhs_binding, rhs_binding) = binop_bindings(data, destination);
+    let allowed_lints = destination.allowed_lints();
+    let lifetime_param = (is_mutable(&data.lhs_ty) || is_mutable(&data.rhs_ty)).then(|| quote!(#lifetime,));
+
+    let (output, element_output) = match destination {
+        Destination::Lhs => (left_hand_type.clone(), Some(elem)),
+        Destination::Rhs => (right_hand_type.clone(), Some(rhs_elem)),
+        Destination::New => (output_target.ty.clone(), (!target.is_mixed()).then_some(elem)),
+    };
+
+    let element_bounds = target.element_bounds(element_trait, element_output);
+
+    quote!{
+        #documentation
+        impl<#lifetime_param #params> #try_trait<#right_hand_type> for #left_hand_type
+        where
+            #element_bounds
+            #bound
+            #(#predicates,)*
+        {
+            type Output = #output;
+
+            #allowed_lints
+            fn #try_method(#lhs_binding, #rhs_binding: #right_hand_type) -> Result<Self::Output, DimensionMismatch> {
+                #length_check
+
+                #fast_path
+
+                #result
+            }
+        }
+    }
+}
+
+/// Generates the elementwise sum as `Add` for the given types,
+/// e.g. `vector_add_impl!(&Vector<T> &mut Vector<T>)`.
+/// 
+/// Each side may be `&mut Vector<T>`, `&Vector<T>` or `Vector<T>`.
+/// A mutably borrowed side receives the sum in place and is returned,
+/// the left hand side if both are, and otherwise the sum is returned by value.
+/// The sum panics if the vectors are not the same size.
+#[proc_macro]
+pub fn vector_add_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplTypes);
+
+    let target = match VectorTarget::from_binop_input(&data, ANY_OWNERSHIP, ANY_OWNERSHIP) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    ops::vector_op_impl("add", &target, &data, true).into()
+}
+
+/// Generates the elementwise difference as `Sub` for the given types,
+/// e.g. `vector_sub_impl!(&mut Vector<T> &Vector<T>)`,
+/// with the ownerships handled the same way as in [vector_add_impl!].
+#[proc_macro]
+pub fn vector_sub_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplTypes);
+
+    let target = match VectorTarget::from_binop_input(&data, ANY_OWNERSHIP, ANY_OWNERSHIP) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    ops::vector_op_impl("sub", &target, &data, true).into()
+}
+
+/// Generates the fallible `TryAdd` for the given types,
+/// which returns a `DimensionMismatch` instead of panicking
+/// when the vectors are not the same size, e.g.
+/// `vector_try_add_impl!(&Vector<T> &Vector<T>)`.
+/// 
+/// The `TryAdd` trait is emitted by [vector_traits!].
+#[proc_macro]
+pub fn vector_try_add_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplTypes);
+
+    let target = match VectorTarget::from_binop_input(&data, ANY_OWNERSHIP, ANY_OWNERSHIP) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    ops::vector_op_impl("try_add", &target, &data, true).into()
+}
+
+/// Generates the fallible `TrySub` for the given types,
+/// the same way as [vector_try_add_impl!].
+#[proc_macro]
+pub fn vector_try_sub_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplTypes);
+
+    let target = match VectorTarget::from_binop_input(&data, ANY_OWNERSHIP, ANY_OWNERSHIP) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    ops::vector_op_impl("try_sub", &target, &data, true).into()
+}
+
+/// Generates an elementwise operator implementation for any
+/// binary operator trait, e.g.
+/// `vector_binop_impl!(Sub, sub, -; &Vector<T> &mut Vector<T>)`.
+/// 
+/// The elements are required to implement the same trait,
+/// and the ownership of the operands is handled the same way
+/// as in [vector_add_impl!].
+/// The types are given as described in the [crate documentation](crate#vector-types).
+#[proc_macro]
+pub fn vector_binop_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorBinOpImpl);
+
+    let mut errors = Vec::new();
+    check_elementwise_operator(&data.operator, &mut errors);
+
+    let target = VectorTarget::from_binop_input(&data.types, ANY_OWNERSHIP, ANY_OWNERSHIP);
+    if let Err(err) = &target {
+        errors.push(err.clone())
+    }
+
+    let target = match combine_errors(errors).and(target) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    let trait_name = &data.trait_name;
+    let operator = &data.operator;
+
+    let op = ElementwiseOp {
+        trait_name: quote!(#trait_name),
+        method: data.method.to_token_stream(),
+        element_trait: quote!(#trait_name),
+        operator: quote!(#operator),
+        mismatch_msg: format!("Vectors with different sizes cannot be combined with '{}'.", operator.to_token_stream())
+    };
+
+    let documentation = vector_documentation(
+        &data.types.lhs_ty,
+        vector_binop_impl_doc(&op, data.types.types_state())
+    );
+
+    elementwise_binop_impl(&op, &target, &data.types, documentation).into()
+}
+
+/// Emits the traits of the named vector operations,
+/// such as `Hadamard` from [vector_hadamard_impl!],
+/// and the `DimensionMismatch` error returned by the fallible
+/// operations, such as `TryAdd` from [vector_try_add_impl!],
+/// along with the `TryOp` naming the failed operation.
+/// 
+/// This must be invoked once in the crate before
+/// the named operations are implemented.
+#[proc_macro]
+pub fn vector_traits(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    if !input.is_empty() {
+        return syn::Error::new_spanned(
+            proc_macro2::TokenStream::from(input),
+            "vector_traits! does not take any input"
+        ).to_compile_error().into()
+    }
+
+    let traits = quote!{
+        /// The elementwise (Hadamard) product of two vectors.
+        pub trait Hadamard<Rhs = Self> {
+            type Output;
+
+            fn hadamard(self, rhs: Rhs) -> Self::Output;
+        }
+
+        /// The elementwise quotient of two vectors.
+        pub trait ElemDiv<Rhs = Self> {
+            type Output;
+
+            fn elem_div(self, rhs: Rhs) -> Self::Output;
+        }
+
+        /// The elementwise remainder of two vectors.
+        pub trait ElemRem<Rhs = Self> {
+            type Output;
+
+            fn elem_rem(self, rhs: Rhs) -> Self::Output;
+        }
+
+        /// The cross product of two vectors of length 3 or 7.
+        pub trait Cross<Rhs = Self> {
+            type Output;
+
+            fn cross(self, rhs: Rhs) -> Self::Output;
+        }
+
+        /// The fallible vector operations, as reported by a `DimensionMismatch`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[non_exhaustive]
+        pub enum TryOp {
+            /// The elementwise sum of `TryAdd`.
+            Add,
+            /// The elementwise difference of `TrySub`.
+            Sub,
+            /// The dot product of `TryDot`.
+            Dot,
+        }
+
+        /// The error of the fallible vector operations,
+        /// such as `TryAdd`, when the vectors are not the same size.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct DimensionMismatch {
+            /// The operation which failed.
+            pub op : TryOp,
+            /// The length of the left hand side.
+            pub lhs_len : usize,
+            /// The length of the right hand side.
+            pub rhs_len : usize,
+        }
+
+        impl ::core::fmt::Display for DimensionMismatch {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let op = match self.op {
+                    TryOp::Add => "add",
+                    TryOp::Sub => "subtract",
+                    TryOp::Dot => "take the dot product of",
+                };
+
+                write!(
+                    f,
+                    "Cannot {} vectors of lengths {} and {}.",
+                    op, self.lhs_len, self.rhs_len
+                )
+            }
+        }
+
+        impl ::std::error::Error for DimensionMismatch {}
+
+        /// The elementwise sum of two vectors, or a `DimensionMismatch`.
+        pub trait TryAdd<Rhs = Self> {
+            type Output;
+
+            fn try_add(self, rhs: Rhs) -> Result<Self::Output, DimensionMismatch>;
+        }
+
+        /// The elementwise difference of two vectors, or a `DimensionMismatch`.
+        pub trait TrySub<Rhs = Self> {
+            type Output;
+
+            fn try_sub(self, rhs: Rhs) -> Result<Self::Output, DimensionMismatch>;
+        }
+
+        /// The dot product of two vectors, or a `DimensionMismatch`.
+        pub trait TryDot<Rhs = Self> {
+            type Output;
+
+            fn try_dot(self, rhs: Rhs) -> Result<Self::Output, DimensionMismatch>;
+        }
+
+        /// The dot product of two vectors summed in the accumulator type `A`,
+        /// into which both elements of each product are converted,
+        /// e.g. `vector1.dot_as::<i32>(&vector2)` for vectors of `i8`.
+        pub trait DotAs<Rhs = Self> {
+            /// The type of the elements on the left hand side.
+            type Elem;
+            /// The type of the elements on the right hand side.
+            type RhsElem;
+
+            fn dot_as<A>(self, rhs: Rhs) -> A
+            where
+                A: From<Self::Elem> + From<Self::RhsElem>
+                    + ::core::ops::Add<Output = A> + ::core::ops::Sub<Output = A>
+                    + ::core::ops::Mul<Output = A> + ::core::ops::AddAssign
+                    + Default + Copy;
+        }
+    };
+
+    traits.into()
+}
+
+/// Emits the `VectorExpr` trait and the expression nodes
+/// of the lazy operators of [vector_expr_impl!],
+/// which are `AddExpr`, `SubExpr` and `ScaleExpr`.
+/// 
+/// This must be invoked once in the crate before
+/// the lazy operators are implemented.
+/// 
+/// `VectorExpr` is an `unsafe` trait, since the evaluation reads the elements
+/// below its `len` without checking the indices again. Another expression type
+/// must keep its length stable and read every index below it soundly.
+#[proc_macro]
+pub fn vector_expr_traits(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    if !input.is_empty() {
+        return syn::Error::new_spanned(
+            proc_macro2::TokenStream::from(input),
+            "vector_expr_traits! does not take any input"
+        ).to_compile_error().into()
+    }
+
+    expr::expr_traits().into()
+}
+
+/// Generates lazy `+`, `-` and scalar `*` operators for the borrowed
+/// vector type, e.g. `vector_expr_impl!(Vector<T>)`, in place of
+/// the `&Vector<T> &Vector<T>` and `&Vector<T> T` forms of
+/// [vector_add_impl!], [vector_sub_impl!] and [vector_scalar_mul_impl!].
+/// 
+/// These operators build expression nodes, such as `AddExpr` and `ScaleExpr`,
+/// which combine with further `+`, `-` and `*`, and are only evaluated
+/// when they are collected with `VectorExpr::eval`, assigned with
+/// `VectorExpr::eval_into` or compared to a vector with `==`.
+/// The whole expression is then computed in one loop over the elements,
+/// without an intermediate vector for each operator, e.g.
+/// 
+/// ```ignore
+/// let sum: Vector<f64> = (&a + &b * 2.0 + &c).eval();
+/// ```
+/// 
+/// The sizes are still checked when the nodes are built.
+/// The generics may be given in front of the type, as described in the
+/// [crate documentation](crate#vector-types), and [vector_expr_traits!] must be invoked first.
+/// 
+/// # Note
+/// The lazy operators take neither the `simd` nor the `parallel` path.
+#[proc_macro]
+pub fn vector_expr_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplType);
+
+    let target = match VectorTarget::from_unary_input(&data, &[Owned]) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    let add_documentation = vector_documentation(
+        &data.ty,
+        vector_expr_impl_doc("Add", "&Vector + VectorExpr", "AddExpr", "&vector1 + &vector2 * 2 + &vector3", "16, 20, 24")
+    );
+    let sub_documentation = vector_documentation(
+        &data.ty,
+        vector_expr_impl_doc("Sub", "&Vector - VectorExpr", "SubExpr", "&vector3 - &vector1 * 2", "5, 4, 3")
+    );
+    let mul_documentation = vector_documentation(
+        &data.ty,
+        vector_expr_impl_doc("Mul", "&Vector * T", "ScaleExpr", "&vector1 * 2 + &vector2", "6, 9, 12")
+    );
+
+    expr::expr_impl(&target, add_documentation, sub_documentation, mul_documentation).into()
+}
+
+/// Generates the elementwise product as `Hadamard::hadamard` for the given
+/// types, e.g. `vector_hadamard_impl!(&Vector<T> &Vector<T>)`, since `Mul`
+/// between two vectors is the dot product of [vector_dot_prod_impl!].
+/// 
+/// The ownerships are handled the same way as in [vector_add_impl!],
+/// and the product panics if the vectors are not the same size.
+/// The `Hadamard` trait is emitted by [vector_traits!].
+#[proc_macro]
+pub fn vector_hadamard_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplTypes);
+
+    let target = match VectorTarget::from_binop_input(&data, ANY_OWNERSHIP, ANY_OWNERSHIP) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    ops::vector_op_impl("hadamard", &target, &data, true).into()
+}
+
+/// Generates the elementwise quotient as `ElemDiv::elem_div` for the given
+/// types, e.g. `vector_elem_div_impl!(&Vector<T> &mut Vector<T>)`,
+/// the same way as [vector_hadamard_impl!].
+#[proc_macro]
+pub fn vector_elem_div_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplTypes);
+
+    let target = match VectorTarget::from_binop_input(&data, ANY_OWNERSHIP, ANY_OWNERSHIP) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    ops::vector_op_impl("elem_div", &target, &data, true).into()
+}
+
+/// Generates the elementwise remainder as `ElemRem::elem_rem` for the given
+/// types, e.g. `vector_elem_rem_impl!(Vector<T> &Vector<T>)`,
+/// the same way as [vector_hadamard_impl!].
+#[proc_macro]
+pub fn vector_elem_rem_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplTypes);
+
+    let target = match VectorTarget::from_binop_input(&data, ANY_OWNERSHIP, ANY_OWNERSHIP) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    ops::vector_op_impl("elem_rem", &target, &data, true).into()
+}
+
+/// The components of the cross product of `a` and `b` for vectors
+/// of length `dim`, which must be either 3 or 7.
+/// 
+/// The 7-dimensional product uses the multiplication table
+/// `e_i x e_(i+1) = e_(i+3)` with indices taken modulo 7.
+fn cross_prod_components(dim : usize) -> Vec<proc_macro2::TokenStream> {
+    let triples: Vec<(usize, usize, usize)> = match dim {
+        3 => vec![(0, 1, 2)],
+        7 => (0..7).map(|i| (i, (i + 1) % 7, (i + 3) % 7)).collect(),
+        _ => unreachable!()
+    };
+
+    (0..dim).map(|k| {
+        let terms = triples.iter().filter_map(|&(x, y, z)| {
+            // Each triple contributes to the component of every
+            // index it contains, rotated so 'k' comes first.
+            let (u, v) = match k {
+                _ if k == x => (y, z),
+                _ if k == y => (z, x),
+                _ if k == z => (x, y),
+                _ => return None
+            };
+            Some(quote!((a[#u] * b[#v] - a[#v] * b[#u])))
+        });
+        quote!(#(#terms)+*)
+    }).collect()
+}
+
+fn cross_prod_impl(
+    target : &VectorTarget,
+    data : &VectorImplTypes,
+    documentation : proc_macro2::TokenStream
+) -> proc_macro2::TokenStream {
+    let VectorTarget {
+        ty,
+        params,
+        predicates,
+        elem,
+        field,
+        construct,
+        length,
+        ..
+    } = target;
+
+    let lifetime = target.borrow_lifetime();
+    let (left_hand_type, right_hand_type) = binop_operand_types(data, &lifetime, ty, ty);
+
+    let components_3d = cross_prod_components(3);
+    let components_7d = cross_prod_components(7);
+
+    let mismatch_msg = "The cross product is only defined for two vectors of length 3 or 7.";
+
+    // The components of a fixed-size vector are built as an array of its size,
+    // whose length is checked at expansion if it is a literal,
+    // and otherwise by a compile-time assertion on the const parameter.
+    let components = match length {
+        Some(length) => match syn::parse2::<LitInt>(length.clone()).map(|lit| lit.base10_parse::<usize>()) {
+            Ok(Ok(3)) => quote!([#(#components_3d),*]),
+            Ok(Ok(7)) => quote!([#(#components_7d),*]),
+            Ok(_) => return syn::Error::new_spanned(
+                length,
+                "the cross product is only defined for vectors of length 3 or 7"
+            ).to_compile_error(),
+            Err(_) => quote!{{
+                const { assert!(#length == 3 || #length == 7, #mismatch_msg) }
+
+                if #length == 3 {
+                    <[#elem; #length]>::try_from(&[#(#components_3d),*][..]).unwrap()
+                } else {
+                    <[#elem; #length]>::try_from(&[#(#components_7d),*][..]).unwrap()
+                }
+            }},
+        },
+        None => quote!{
+            match (a.len(), b.len()) {
+                (3, 3) => vec![#(#components_3d),*],
+                (7, 7) => vec![#(#components_7d),*],
+                _ => panic!(#mismatch_msg)
+            }
+        },
+    };
+
+    let product = quote!{
+        let a = &self.#field[..];
+        let b = &rhs.#field[..];
+
+        let params = #components;
+    };
+
+    let (generics, output, result) = match (is_mutable(&data.lhs_ty), is_mutable(&data.rhs_ty)) {
+        (true, _) => (
+            quote!(<#lifetime, #params>),
+            quote!(&#lifetime mut #ty),
+            quote!{
+                for (item, value) in self.#field.iter_mut().zip(params) {
+                    *item = value
+                }
+                self
+            }
+        ),
+        (_, true) => (
+            quote!(<#lifetime, #params>),
+            quote!(&#lifetime mut #ty),
+            quote!{
+                for (item, value) in rhs.#field.iter_mut().zip(params) {
+                    *item = value
+                }
+                rhs
+            }
+        ),
+        (false, false) => (
+            quote!(<#params>),
+            quote!(#ty),
+            quote!(#construct)
+        ),
+    };
+
+    quote!{
+        #documentation
+        impl #generics Cross<#right_hand_type> for #left_hand_type
+        where
+            #elem: Copy + Mul<Output = #elem> + Sub<Output = #elem> + Add<Output = #elem>,
+            #(#predicates,)*
+        {
+            type Output = #output;
+
+            fn cross(self, rhs: #right_hand_type) -> Self::Output {
+                #product
+
+                #result
+            }
+        }
+    }
+}
+
+/// Generates the cross product as `Cross::cross` for the given types,
+/// e.g. `vector_cross_prod_impl!(&Vector<T> &Vector<T>)`, for vectors
+/// of length 3 or 7, with the ownerships handled the same way as in
+/// [vector_add_impl!].
+/// 
+/// Both sides must have the same element type. The product panics unless
+/// the vectors are both of length 3 or both of length 7, while fixed-size
+/// vectors of any other length are rejected at compile time.
+/// The `Cross` trait is emitted by [vector_traits!].
+#[proc_macro]
+pub fn vector_cross_prod_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplTypes);
+
+    let target = match VectorTarget::from_binop_input(&data, ANY_OWNERSHIP, ANY_OWNERSHIP) {
+        Ok(target) if target.is_mixed() => return syn::Error::new_spanned(
+            &data.rhs_ty,
+            "the cross product expects the same element type on both sides"
+        ).to_compile_error().into(),
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    ops::vector_op_impl("cross", &target, &data, true).into()
+}
+
+fn elementwise_assign_impl(
+    op : &ElementwiseOp,
+    target : &VectorTarget,
+    data : &VectorImplTypes,
+    documentation : proc_macro2::TokenStream
+) -> proc_macro2::TokenStream {
+    let ElementwiseOp {
+        trait_name,
+        method,
+        element_trait,
+        operator,
+        mismatch_msg
+    } = op;
+
+    let VectorTarget {
+        ty,
+        params,
+        predicates,
+        field,
+        rhs_ty,
+        ..
+    } = target;
+
+    let length_check = target.length_check(quote!(panic!(#mismatch_msg)));
+
+    let FastPath { bound, code: fast_path } =
+        parallel::zip(target, quote!(*a #operator *b), Destination::Lhs, quote!())
+            .or(simd::elementwise(target, operator, Destination::Lhs, quote!()));
+
+    let right_hand_type = match data.types_state() {
+        // Vector, &Vector
+        (false, false, true, false) => quote!(&#rhs_ty),
+
+        // Vector, Vector
+        (false, false, false, false) => quote!(#rhs_ty),
+
+        _ => unreachable!()
+    };
+
+    let element_bounds = target.element_bounds(element_trait, None);
+
+    quote!{
+        #documentation
+        impl<#params> #trait_name<#right_hand_type> for #ty
+        where
+            #element_bounds
+            #bound
+            #(#predicates,)*
+        {
+            fn #method(&mut self, rhs: #right_hand_type) {
+                #length_check
+
+                #fast_path
+
+                for (a, b) in self.#field.iter_mut().zip(rhs.#field.iter()) {
+                    *a #operator *b
+                }
+            }
+        }
+    }
+}
+
+/// Generates `AddAssign` for the given types, e.g.
+/// `vector_add_assign_impl!(Vector<T> &Vector<T>)`, so that `v += &w` adds
+/// each element of `w` to the element of `v` at the same position.
+/// 
+/// The left hand side is the owned vector, and the right hand side
+/// is either `&Vector<T>` or `Vector<T>`.
+/// The assignment panics if the vectors are not the same size.
+#[proc_macro]
+pub fn vector_add_assign_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplTypes);
+
+    let target = match VectorTarget::from_binop_input(&data, ASSIGN_LHS, ASSIGN_RHS) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    ops::vector_op_impl("add_assign", &target, &data, true).into()
+}
+
+/// Generates `SubAssign` for the given types, e.g.
+/// `vector_sub_assign_impl!(Vector<T> Vector<T>)`,
+/// the same way as [vector_add_assign_impl!].
+#[proc_macro]
+pub fn vector_sub_assign_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplTypes);
+
+    let target = match VectorTarget::from_binop_input(&data, ASSIGN_LHS, ASSIGN_RHS) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    ops::vector_op_impl("sub_assign", &target, &data, true).into()
+}
+
+/// The where clause predicates of the dot product, such as
+/// `T: Copy + Mul<U>, U: Copy, <T as Mul<U>>::Output: Copy + AddAssign + Default,`
+/// if the elements on the right hand side are of another type `U`,
+/// or `T: Copy, i32: From<T> + Mul<Output = i32> + AddAssign + Default + Copy,`
+/// with the `accumulator` type `i32`, along with the operators
+/// needed by the `summation`.
+fn dot_prod_bounds(
+    target : &VectorTarget,
+    accumulator : Option<&proc_macro2::TokenStream>,
+    summation : Summation
+) -> proc_macro2::TokenStream {
+    let VectorTarget {
+        elem,
+        rhs_elem,
+        ..
+    } = target;
+
+    let product_ty = match accumulator {
+        Some(accumulator) => accumulator.clone(),
+        None if target.is_mixed() => quote!(<#elem as Mul<#rhs_elem>>::Output),
+        None => elem.clone(),
+    };
+
+    let summation_bounds = match summation {
+        Summation::Naive => quote!(),
+        Summation::Kahan => quote!(#product_ty: Add<Output = #product_ty> + Sub<Output = #product_ty>,),
+        Summation::Pairwise => quote!(#product_ty: Add<Output = #product_ty>,),
+    };
+
+    let bounds = match accumulator {
+        Some(accumulator) if target.is_mixed() => quote!{
+            #elem: Copy,
+            #rhs_elem: Copy,
+            #accumulator: From<#elem> + From<#rhs_elem> + Mul<Output = #accumulator> + AddAssign + Default + Copy,
+        },
+        Some(accumulator) => quote!{
+            #elem: Copy,
+            #accumulator: From<#elem> + Mul<Output = #accumulator> + AddAssign + Default + Copy,
+        },
+        None if target.is_mixed() => quote!{
+            #elem: Copy + Mul<#rhs_elem>,
+            #rhs_elem: Copy,
+            <#elem as Mul<#rhs_elem>>::Output: Copy + AddAssign + Default,
+        },
+        None => quote!(#elem: Copy + Mul<Output = #elem> + AddAssign + Default,)
+    };
+
+    quote!(#bounds #summation_bounds)
+}
+
+fn dot_prod_impl(
+    target : &VectorTarget,
+    data : &VectorImplTypes,
+    accumulator : Option<&Type>,
+    summation : Summation,
+    documentation : proc_macro2::TokenStream
+) -> proc_macro2::TokenStream {
+    let VectorTarget {
+        ty,
+        params,
+        predicates,
+        rhs_ty,
+        ..
+    } = target;
+
+    let lifetime = target.borrow_lifetime();
+    let (left_hand_type, right_hand_type) = binop_operand_types(data, &lifetime, ty, rhs_ty);
+
+    let length_check = target.length_check(quote!{
+        panic!("Cannot find dot product of two differently sized vectors.")
+    });
+
+    let accumulator = accumulator.map(ToTokens::to_token_stream);
+    let bounds = dot_prod_bounds(target, accumulator.as_ref(), summation);
+
+    // The parallel and SIMD paths sum the products naively in the element type.
+    let (product_ty, dot_product, fast_path) = match &accumulator {
+        Some(accumulator) => (
+            accumulator.clone(),
+            target.dot_product(Some(accumulator), summation),
+            FastPath::none()
+        ),
+        None if summation != Summation::Naive => {
+            let output = target.binop_output(&quote!(Mul));
+            (output.elem.clone(), output.dot_product(None, summation), FastPath::none())
+        },
+        None => {
+            let output = target.binop_output(&quote!(Mul));
+            (
+                output.elem.clone(),
+                output.dot_product(None, summation),
+                parallel::dot(target, |product| product).or(simd::dot(target, |product| product))
+            )
+        },
+    };
+    let FastPath { bound, code: fast_path } = fast_path;
+
+    let generics = if is_mutable(&data.lhs_ty) || is_mutable(&data.rhs_ty) {
+        quote!(<#lifetime, #params>)
+    } else {
+        quote!(<#params>)
+    };
+
+    quote!{
+        #documentation
+        impl #generics Mul<#right_hand_type> for #left_hand_type
+        where
+            #bounds
+            #bound
+            #(#predicates,)*
+        {
+            type Output = #product_ty;
+
+            fn mul(self, rhs: #right_hand_type) -> Self::Output {
+                #length_check
+
+                #fast_path
+
+                #dot_product
+
+                product
+            }
+        }
+    }
+}
+
+/// Generates the dot product as `Mul` for the given types,
+/// e.g. `vector_dot_prod_impl!(&Vector<T> &Vector<T>)`.
+/// 
+/// The products are summed in the element type, unless an accumulator
+/// type is given after the types, e.g. `&Vector<i8> &Vector<i8> => i32`,
+/// into which both elements of each product are converted with `From`.
+/// 
+/// For floating point elements, the summation may be chosen last,
+/// e.g. `&Vector<f32> &Vector<f32>, summation = kahan`:
+/// - `naive`, the default, adds the products in eight interleaved lanes.
+/// - `kahan` carries the rounding error of each addition into the next,
+///   which requires the product type to implement `Add` and `Sub`.
+/// - `pairwise` adds the sums of blocks of products as a balanced tree,
+///   which requires the product type to implement `Add`.
+/// 
+/// The SIMD and parallel paths are only taken with the naive summation.
+#[proc_macro]
+pub fn vector_dot_prod_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as DotProdImpl);
+
+    let target = match VectorTarget::from_binop_input(&data.types, ANY_OWNERSHIP, ANY_OWNERSHIP) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    ops::dot_op_impl("dot", &target, &data.types, data.accumulator.as_ref(), data.summation, true).into()
+}
+
+/// The fallible counterpart of [dot_prod_impl], implementing `TryDot`.
+fn try_dot_prod_impl(
+    target : &VectorTarget,
+    data : &VectorImplTypes,
+    accumulator : Option<&Type>,
+    summation : Summation,
+    documentation : proc_macro2::TokenStream
+) -> proc_macro2::TokenStream {
+    let VectorTarget {
+        ty,
+        params,
+        predicates,
+        field,
+        rhs_ty,
+        ..
+    } = target;
+
+    let lifetime = target.borrow_lifetime();
+    let (left_hand_type, right_hand_type) = binop_operand_types(data, &lifetime, ty, rhs_ty);
+
+    let length_check = target.length_check(quote!{
+        return Err(DimensionMismatch {
+            op: TryOp::Dot,
+            lhs_len: self.#field.len(),
+            rhs_len: rhs.#field.len(),
+        })
+    });
+
+    let accumulator = accumulator.map(ToTokens::to_token_stream);
+    let bounds = dot_prod_bounds(target, accumulator.as_ref(), summation);
+
+    let wrap = |product| quote!(Ok(#product));
+    let (product_ty, dot_product, fast_path) = match &accumulator {
+        Some(accumulator) => (
+            accumulator.clone(),
+            target.dot_product(Some(accumulator), summation),
+            FastPath::none()
+        ),
+        None if summation != Summation::Naive => {
+            let output = target.binop_output(&quote!(Mul));
+            (output.elem.clone(), output.dot_product(None, summation), FastPath::none())
+        },
+        None => {
+            let output = target.binop_output(&quote!(Mul));
+            (
+                output.elem.clone(),
+                output.dot_product(None, summation),
+                parallel::dot(target, wrap).or(simd::dot(target, wrap))
+            )
+        },
+    };
+    let FastPath { bound, code: fast_path } = fast_path;
+
+    let generics = if is_mutable(&data.lhs_ty) || is_mutable(&data.rhs_ty) {
+        quote!(<#lifetime, #params>)
+    } else {
+        quote!(<#params>)
+    };
+
+    quote!{
+        #documentation
+        impl #generics TryDot<#right_hand_type> for #left_hand_type
+        where
+            #bounds
+            #bound
+            #(#predicates,)*
+        {
+            type Output = #product_ty;
+
+            fn try_dot(self, rhs: #right_hand_type) -> Result<Self::Output, DimensionMismatch> {
+                #length_check
+
+                #fast_path
+
+                #dot_product
+
+                Ok(product)
+            }
+        }
+    }
+}
+
+/// The dot product as `DotAs`, generic over the accumulator type.
+fn dot_as_impl(
+    target : &VectorTarget,
+    data : &VectorImplTypes,
+    summation : Summation,
+    documentation : proc_macro2::TokenStream
+) -> proc_macro2::TokenStream {
+    let VectorTarget {
+        ty,
+        params,
+        predicates,
+        elem,
+        rhs_ty,
+        rhs_elem,
+        ..
+    } = target;
+
+    let lifetime = target.borrow_lifetime();
+    let (left_hand_type, right_hand_type) = binop_operand_types(data, &lifetime, ty, rhs_ty);
+
+    let length_check = target.length_check(quote!{
+        panic!("Cannot find dot product of two differently sized vectors.")
+    });
+
+    let dot_product = target.dot_product(Some(&quote!(A)), summation);
+
+    let generics = if is_mutable(&data.lhs_ty) || is_mutable(&data.rhs_ty) {
+        quote!(<#lifetime, #params>)
+    } else {
+        quote!(<#params>)
+    };
+
+    quote!{
+        #documentation
+        impl #generics DotAs<#right_hand_type> for #left_hand_type
+        where
+            #elem: Copy,
+            #rhs_elem: Copy,
+            #(#predicates,)*
+        {
+            type Elem = #elem;
+            type RhsElem = #rhs_elem;
+
+            fn dot_as<A>(self, rhs: #right_hand_type) -> A
+            where
+                A: From<#elem> + From<#rhs_elem>
+                    + Add<Output = A> + Sub<Output = A> + Mul<Output = A> + AddAssign
+                    + Default + Copy,
+            {
+                #length_check
+
+                #dot_product
+
+                product
+            }
+        }
+    }
+}
+
+/// Generates `DotAs` for the given types, e.g.
+/// `vector_dot_as_impl!(&Vector<T> &Vector<T>)`,
+/// whose `dot_as` method sums the products in any accumulator type
+/// the elements convert into, the same way as [vector_dot_prod_impl!].
+/// The summation may be chosen the same way, e.g.
+/// `vector_dot_as_impl!(&Vector<T> &Vector<T>, summation = pairwise)`.
+/// 
+/// The `DotAs` trait is emitted by [vector_traits!].
+#[proc_macro]
+pub fn vector_dot_as_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as DotProdImpl);
+
+    if let Some(accumulator) = &data.accumulator {
+        return syn::Error::new_spanned(
+            accumulator,
+            "the accumulator type of `dot_as` is chosen when calling it, e.g. `dot_as::<i32>`"
+        ).to_compile_error().into()
+    }
+
+    let target = match VectorTarget::from_binop_input(&data.types, ANY_OWNERSHIP, ANY_OWNERSHIP) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    ops::dot_op_impl("dot_as", &target, &data.types, None, data.summation, true).into()
+}
+
+/// Generates the fallible `TryDot` for the given types,
+/// the same way as [vector_try_add_impl!],
+/// with an optional accumulator type as in [vector_dot_prod_impl!].
+#[proc_macro]
+pub fn vector_try_dot_prod_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as DotProdImpl);
+
+    let target = match VectorTarget::from_binop_input(&data.types, ANY_OWNERSHIP, ANY_OWNERSHIP) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    ops::dot_op_impl("try_dot", &target, &data.types, data.accumulator.as_ref(), data.summation, true).into()
+}
+
+/// The pieces of a vector-scalar operator that differ between
+/// the generated implementations.
+struct ScalarOp {
+    /// The trait being implemented for the vector.
+    trait_name : proc_macro2::TokenStream,
+    /// The method of `trait_name`.
+    method : proc_macro2::TokenStream,
+    /// The operator applied to each element and the scalar.
+    operator : proc_macro2::TokenStream,
+    /// Whether the scalar is the left operand of `operator`,
+    /// as in `rhs * a`, which keeps the order of the elements'
+    /// own multiplication for `Mul`.
+    scalar_first : bool,
+}
+
+impl ScalarOp {
+    fn mul() -> Self {
+        ScalarOp {
+            trait_name: quote!(Mul),
+            method: quote!(mul),
+            operator: quote!(*),
+            scalar_first: true
+        }
+    }
+
+    fn div() -> Self {
+        ScalarOp {
+            trait_name: quote!(Div),
+            method: quote!(div),
+            operator: quote!(/),
+            scalar_first: false
+        }
+    }
+
+    fn rem() -> Self {
+        ScalarOp {
+            trait_name: quote!(Rem),
+            method: quote!(rem),
+            operator: quote!(%),
+            scalar_first: false
+        }
+    }
+
+    /// Applies the operator to the `element` and the scalar `rhs`.
+    fn apply(&self, element: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let operator = &self.operator;
+
+        if self.scalar_first {
+            quote!(rhs #operator #element)
+        } else {
+            quote!(#element #operator rhs)
+        }
+    }
+}
+
+fn scalar_binop_impl(
+    op : &ScalarOp,
+    target : &VectorTarget,
+    data : &VectorImplTypes,
+    documentation : proc_macro2::TokenStream
+) -> proc_macro2::TokenStream {
+    let ScalarOp {
+        trait_name,
+        method,
+        ..
+    } = op;
+
+    let VectorTarget {
+        ty,
+        params,
+        predicates,
+        elem,
+        field,
+        construct,
+        ..
+    } = target;
+
+    let type_state = (
+        is_borrow(&data.lhs_ty), 
+        is_mutable(&data.lhs_ty),
+    );
+
+    let element = op.apply(quote!(*a));
+    let in_place = op.apply(quote!(*item));
+    let build = target.map(element.clone());
+    let allowed_lints = Destination::Lhs.allowed_lints();
+    let lifetime = target.borrow_lifetime();
+
+    // An owned vector is written in place, like a mutably borrowed one.
+    let FastPath { bound, code: fast_path } = match type_state {
+        (true, false) => parallel::map(target, element, Destination::New, construct.clone()),
+        _ => parallel::map(target, quote!(*a = #element), Destination::Lhs, quote!(self)),
+    };
+
+    let left_hand_type = match type_state {
+        //&mut Vector<T> * T
+        (true, true) => quote!(&#lifetime mut #ty),
+
+        //&Vector<T> * T
+        (true, false) => quote!(&#ty),
+
+        //Vector<T> * T
+        (false, false) => quote!(#ty),
+
+        _ => unreachable!(),
+    };
+
+    match type_state {
+        (true, true) => quote!{
+            #documentation
+            impl<#lifetime, #params> #trait_name<#elem> for #left_hand_type
+            where
+                #elem: Copy + #trait_name<Output = #elem>,
+                #bound
+                #(#predicates,)*
+            {
+                type Output = &#lifetime mut #ty;
+                
+                #allowed_lints
+                fn #method(self, rhs: #elem) -> Self::Output {
+                    #fast_path
+
+                    for item in self.#field.iter_mut() {
+                        *item = #in_place
+                    }
+                    self
+                }
+            }
+        },
+
+        (true, false) => quote!{
+            #documentation
+            impl<#params> #trait_name<#elem> for #left_hand_type
+            where
+                #elem: Copy + #trait_name<Output = #elem>,
+                #bound
+                #(#predicates,)*
+            {
+                type Output = #ty;
+            
+                fn #method(self, rhs: #elem) -> Self::Output {
+                    #fast_path
+
+                    #build
+                }
+            }
+        },
+
+        (false, false) => quote!{
+            #documentation
+            impl<#params> #trait_name<#elem> for #left_hand_type
+            where
+                #elem: Copy + #trait_name<Output = #elem>,
+                #bound
+                #(#predicates,)*
+            {
+                type Output = #ty;
+
+                #allowed_lints
+                fn #method(mut self, rhs: #elem) -> Self::Output {
+                    #fast_path
+
+                    for item in self.#field.iter_mut() {
+                        *item = #in_place
+                    }
+                    self
+                }
+            }
+        },
+
+        _ => unreachable!(),
+    }
+}
+
+/// Generates the multiplication of a vector by its element type as `Mul`,
+/// e.g. `vector_scalar_mul_impl!(&Vector<T> T)`, which computes `rhs * a`
+/// for each element `a`, keeping the scalar as the left factor.
+/// 
+/// A `&mut Vector<T>` is scaled in place and returned,
+/// while `&Vector<T>` and `Vector<T>` return the scaled vector.
+/// The scalar on the left, as in `2.0 * &vector`, is generated by
+/// [scalar_vector_mul_impl!].
+#[proc_macro]
+pub fn vector_scalar_mul_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplTypes);
+
+    let target = match VectorTarget::from_scalar_input(&data, ANY_OWNERSHIP) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    ops::vector_op_impl("scalar_mul", &target, &data, true).into()
+}
+
+/// Generates the division of each element by a scalar as `Div`,
+/// e.g. `vector_scalar_div_impl!(&mut Vector<T> T)`,
+/// with the ownerships handled the same way as in [vector_scalar_mul_impl!].
+/// The elements are divided as they are, so integer vectors are
+/// divided with truncation.
+#[proc_macro]
+pub fn vector_scalar_div_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplTypes);
+
+    let target = match VectorTarget::from_scalar_input(&data, ANY_OWNERSHIP) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    ops::vector_op_impl("scalar_div", &target, &data, true).into()
+}
+
+/// Generates the remainder of each element by a scalar as `Rem`,
+/// e.g. `vector_scalar_rem_impl!(Vector<T> T)`,
+/// with the ownerships handled the same way as in [vector_scalar_mul_impl!].
+#[proc_macro]
+pub fn vector_scalar_rem_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplTypes);
+
+    let target = match VectorTarget::from_scalar_input(&data, ANY_OWNERSHIP) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    ops::vector_op_impl("scalar_rem", &target, &data, true).into()
+}
+
+/// The implementations of `scalar * vector` for the `target`,
+/// whose element type is the scalar, one for each ownership of the vector.
+/// 
+/// The documentation is only generated if `documented`,
+/// and only for the upstream `Vector`.
+fn left_scalar_mul_impl(target : &VectorTarget, documented : bool) -> proc_macro2::TokenStream {
+    let VectorTarget {
+        ty,
+        params,
+        predicates,
+        elem,
+        field,
+        ..
+    } = target;
+
+    let document = |type_state| if documented {
+        vector_documentation(&syn::parse_quote!(#ty), scalar_vector_mul_impl_doc(type_state, &syn::parse_quote!(#elem)))
+    } else {
+        quote!()
+    };
+
+    let mut_documentation = document((true, true));
+    let borrow_documentation = document((true, false));
+    let owned_documentation = document((false, false));
+
+    let build = target.map_operand(quote!(rhs), quote!(self * *a));
+    let allowed_lints = Destination::Rhs.allowed_lints();
+    let lifetime = target.borrow_lifetime();
+
+    quote!{
+        #mut_documentation
+        impl<#lifetime, #params> Mul<&#lifetime mut #ty> for #elem
+        where
+            #(#predicates,)*
+        {
+            type Output = &#lifetime mut #ty;
+
+            #allowed_lints
+            fn mul(self, rhs: &#lifetime mut #ty) -> Self::Output {
+                for item in rhs.#field.iter_mut() {
+                    *item = self * *item
+                }
+                rhs
+            }
+        }
+
+        #borrow_documentation
+        impl<#params> Mul<&#ty> for #elem
+        where
+            #(#predicates,)*
+        {
+            type Output = #ty;
+
+            fn mul(self, rhs: &#ty) -> Self::Output {
+                #build
+            }
+        }
+
+        #owned_documentation
+        impl<#params> Mul<#ty> for #elem
+        where
+            #(#predicates,)*
+        {
+            type Output = #ty;
+
+            #allowed_lints
+            fn mul(self, mut rhs: #ty) -> Self::Output {
+                for item in rhs.#field.iter_mut() {
+                    *item = self * *item
+                }
+                rhs
+            }
+        }
+    }
+}
+
+/// The error for a vector type whose element type cannot be replaced
+/// by the scalar types of `scalar_vector_mul_impl!`.
+fn scalar_vector_mul_error(ty: impl ToTokens) -> syn::Error {
+    syn::Error::new_spanned(
+        ty,
+        "expected the element type to be a generic parameter, \
+         which is replaced by each scalar type, e.g. `Vector<T>`"
+    )
+}
+
+/// Generates the multiplication of a vector by a scalar on its left,
+/// such as `3.0 * &vector`, for each of the given primitive scalar types,
+/// e.g. `scalar_vector_mul_impl!(i32, f32, f64)`.
+/// 
+/// Coherence rules out a generic `impl<T> Mul<Vector<T>> for T`,
+/// so the impls are generated for `Vector<i32>`, `Vector<f32>`
+/// and so on, for the `&mut`, borrowed and owned vector.
+/// 
+/// Another vector type can be given in front of the scalar types,
+/// e.g. `scalar_vector_mul_impl!(Point<T>: f32, f64)`,
+/// in which the element type `T` is replaced by each scalar type.
+/// The generics may be given in front of the type, as described in the
+/// [crate documentation](crate#vector-types), e.g. `impl<T, U> Tagged<T, U>: f32`.
+#[proc_macro]
+pub fn scalar_vector_mul_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as ScalarVectorMulImpl);
+
+    let mut errors = Vec::new();
+    for scalar in data.types.iter() {
+        check_ownership(scalar, &[Owned], "scalar", &mut errors);
+    }
+
+    let ty: Type = match &data.ty {
+        Some(ty) => {
+            check_ownership(ty, &[Owned], "vector", &mut errors);
+            (**ty).clone()
+        },
+        None => syn::parse_quote!(Vector<T>),
+    };
+
+    let target = VectorTarget::from_input(&data.generics, &ty);
+    if let Err(err) = &target {
+        errors.push(err.clone())
+    }
+
+    let target = match combine_errors(errors).and(target) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    let mut implementations = proc_macro2::TokenStream::new();
+
+    for scalar in data.types.iter() {
+        match target.instantiate(scalar) {
+            Ok(Some(target)) => implementations.extend(left_scalar_mul_impl(&target, true)),
+            Ok(None) => return scalar_vector_mul_error(&ty).to_compile_error().into(),
+            Err(err) => return err.to_compile_error().into(),
+        }
+    }
+
+    implementations.into()
+}
+
+fn scalar_mul_assign_impl(
+    target : &VectorTarget,
+    documentation : proc_macro2::TokenStream
+) -> proc_macro2::TokenStream {
+    let VectorTarget {
+        ty,
+        params,
+        predicates,
+        elem,
+        field,
+        ..
+    } = target;
+
+    quote!{
+        #documentation
+        impl<#params> MulAssign<#elem> for #ty
+        where
+            #elem: Copy + MulAssign,
+            #(#predicates,)*
+        {
+            fn mul_assign(&mut self, rhs: #elem) {
+                for item in self.#field.iter_mut() {
+                    *item *= rhs
+                }
+            }
+        }
+    }
+}
+
+/// Generates `MulAssign` by the element type for the owned vector,
+/// e.g. `vector_scalar_mul_assign_impl!(Vector<T> T)`,
+/// so that `v *= 2.0` multiplies each element in place.
+#[proc_macro]
+pub fn vector_scalar_mul_assign_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplTypes);
+
+    let target = match VectorTarget::from_scalar_input(&data, ASSIGN_LHS) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    ops::vector_op_impl("scalar_mul_assign", &target, &data, true).into()
+}
+
+fn neg_impl(
+    target : &VectorTarget,
+    data : &VectorImplType,
+    documentation : proc_macro2::TokenStream
+) -> proc_macro2::TokenStream {
+    let VectorTarget {
+        ty,
+        params,
+        predicates,
+        elem,
+        field,
+        ..
+    } = target;
+
+    let build = target.map(quote!(-*a));
+    let lifetime = target.borrow_lifetime();
+
+    match (is_borrow(&data.ty), is_mutable(&data.ty)) {
+        //-&mut Vector<T>
+        (true, true) => quote!{
+            #documentation
+            impl<#lifetime, #params> Neg for &#lifetime mut #ty
+            where
+                #elem: Copy + Neg<Output = #elem>,
+                #(#predicates,)*
+            {
+                type Output = &#lifetime mut #ty;
+
+                fn neg(self) -> Self::Output {
+                    for item in self.#field.iter_mut() {
+                        *item = -*item
+                    }
+                    self
+                }
+            }
+        },
+
+        //-&Vector<T>
+        (true, false) => quote!{
+            #documentation
+            impl<#params> Neg for &#ty
+            where
+                #elem: Copy + Neg<Output = #elem>,
+                #(#predicates,)*
+            {
+                type Output = #ty;
+
+                fn neg(self) -> Self::Output {
+                    #build
+                }
+            }
+        },
+
+        //-Vector<T>
+        (false, false) => quote!{
+            #documentation
+            impl<#params> Neg for #ty
+            where
+                #elem: Copy + Neg<Output = #elem>,
+                #(#predicates,)*
+            {
+                type Output = #ty;
+
+                fn neg(mut self) -> Self::Output {
+                    for item in self.#field.iter_mut() {
+                        *item = -*item
+                    }
+                    self
+                }
+            }
+        },
+
+        _ => unreachable!(),
+    }
+}
+
+/// Generates the negation of each element as `Neg` for the given type,
+/// e.g. `vector_neg_impl!(&Vector<T>)`.
+/// 
+/// A `&mut Vector<T>` is negated in place and returned,
+/// while `&Vector<T>` and `Vector<T>` return the negated vector.
+#[proc_macro]
+pub fn vector_neg_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplType);
+
+    let target = match VectorTarget::from_unary_input(&data, ANY_OWNERSHIP) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    let documentation = vector_documentation(
+        &data.ty,
+        vector_neg_impl_doc((is_borrow(&data.ty), is_mutable(&data.ty)))
+    );
+
+    neg_impl(&target, &data, documentation).into()
+}
+
+/// The target of a matrix operator, whose operands must be
+/// the same matrix type passed in a supported way, e.g. `&Matrix<T> Matrix<T>`.
+/// Its `field` holds the rows, such as a `Vec<Vec<T>>`.
+fn matrix_target(data : &VectorImplTypes) -> syn::Result<VectorTarget> {
+    let target = VectorTarget::from_binop_input(data, ANY_OWNERSHIP, ANY_OWNERSHIP)?;
+
+    let mut errors = Vec::new();
+
+    if target.is_mixed() {
+        errors.push(syn::Error::new_spanned(
+            owned_type(&data.rhs_ty),
+            format!(
+                "expected the same matrix type on both sides, e.g. `{}`",
+                display_type(owned_type(&data.lhs_ty))
+            )
+        ));
+    }
+
+    if target.length.is_some() {
+        errors.push(syn::Error::new_spanned(
+            owned_type(&data.lhs_ty),
+            "fixed-size matrices are not supported"
+        ));
+    }
+
+    combine_errors(errors)?;
+    Ok(target)
+}
+
+/// The number of rows and columns of the matrix `operand` of the `target`.
+fn matrix_lengths(target : &VectorTarget, operand : proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let field = &target.field;
+
+    quote!((#operand.#field.len(), #operand.#field.first().map_or(0, |row| row.len())))
+}
+
+fn matrix_elementwise_binop_impl(
+    op : &ElementwiseOp,
+    target : &VectorTarget,
+    data : &VectorImplTypes,
+    documentation : proc_macro2::TokenStream
+) -> proc_macro2::TokenStream {
+    let ElementwiseOp {
+        trait_name,
+        method,
+        element_trait,
+        operator,
+        mismatch_msg
+    } = op;
+
+    let VectorTarget {
+        ty,
+        params,
+        predicates,
+        elem,
+        field,
+        construct,
+        ..
+    } = target;
+
+    let lifetime = target.borrow_lifetime();
+    let (left_hand_type, right_hand_type) = binop_operand_types(data, &lifetime, ty, ty);
+
+    let lhs_lengths = matrix_lengths(target, quote!(self));
+    let rhs_lengths = matrix_lengths(target, quote!(rhs));
+
+    let destination = binop_destination(target, data);
+    let (lhs_binding, rhs_binding) = binop_bindings(data, destination);
+    let allowed_lints = destination.allowed_lints();
+    let lifetime_param = (is_mutable(&data.lhs_ty) || is_mutable(&data.rhs_ty)).then(|| quote!(#lifetime,));
+
+    let (output, body) = match destination {
+        Destination::Lhs => (
+            left_hand_type.clone(),
+            quote!{
+                for (lhs_row, rhs_row) in self.#field.iter_mut().zip(rhs.#field.iter()) {
+                    for (a, b) in lhs_row.iter_mut().zip(rhs_row.iter()) {
+                        *a = *a #operator *b
+                    }
+                }
+
+                self
+            }
+        ),
+        Destination::Rhs => (
+            right_hand_type.clone(),
+            quote!{
+                for (lhs_row, rhs_row) in self.#field.iter().zip(rhs.#field.iter_mut()) {
+                    for (a, b) in lhs_row.iter().zip(rhs_row.iter_mut()) {
+                        *b = *a #operator *b
+                    }
+                }
+
+                rhs
+            }
+        ),
+        Destination::New => (
+            ty.clone(),
+            quote!{
+                let params: Vec<Vec<#elem>> = self.#field.iter()
+                    .zip(rhs.#field.iter())
+                    .map(|(lhs_row, rhs_row)| lhs_row.iter()
+                        .zip(rhs_row.iter())
+                        .map(|(a, b)| *a #operator *b)
+                        .collect())
+                    .collect();
+
+                #construct
+            }
+        ),
+    };
+
+    quote!{
+        #documentation
+        impl<#lifetime_param #params> #trait_name<#right_hand_type> for #left_hand_type
+        where
+            #elem: #element_trait<Output = #elem> + Copy,
+            #(#predicates,)*
+        {
+            type Output = #output;
+
+            #allowed_lints
+            fn #method(#lhs_binding, #rhs_binding: #right_hand_type) -> Self::Output {
+                if #lhs_lengths != #rhs_lengths {
+                    panic!(#mismatch_msg)
+                }
+
+                #body
+            }
+        }
+    }
+}
+
+/// Generates the elementwise sum of two matrices as `Add` for the given types,
+/// e.g. `matrix_add_impl!(&Matrix<T> &mut Matrix<T>)`, where the matrix type
+/// keeps its rows in a `list` field, such as a `Vec<Vec<T>>`.
+/// 
+/// The ownerships are handled the same way as in [vector_add_impl!],
+/// and the sum panics if the matrices do not have the same dimensions.
+#[proc_macro]
+pub fn matrix_add_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplTypes);
+
+    let target = match matrix_target(&data) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    let op = ElementwiseOp {
+        trait_name: quote!(Add),
+        method: quote!(add),
+        element_trait: quote!(Add),
+        operator: quote!(+),
+        mismatch_msg: String::from("Matrices with different dimensions cannot be added together.")
+    };
+
+    let example = OperatorExample {
+        lhs: "[1, 2], [3, 4]",
+        rhs: "[5, 6], [7, 8]",
+        result: "[6, 8], [10, 12]",
+        panic: "the matrices do not have the same dimensions"
+    };
+
+    let documentation = matrix_documentation(
+        &data.lhs_ty,
+        matrix_binop_impl_doc("addition", "Add", "+", &example, data.types_state())
+    );
+
+    matrix_elementwise_binop_impl(&op, &target, &data, documentation).into()
+}
+
+/// Generates the elementwise difference of two matrices as `Sub`
+/// for the given types, e.g. `matrix_sub_impl!(Matrix<T> &Matrix<T>)`,
+/// the same way as [matrix_add_impl!].
+#[proc_macro]
+pub fn matrix_sub_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplTypes);
+
+    let target = match matrix_target(&data) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    let op = ElementwiseOp {
+        trait_name: quote!(Sub),
+        method: quote!(sub),
+        element_trait: quote!(Sub),
+        operator: quote!(-),
+        mismatch_msg: String::from("Matrices with different dimensions cannot be subtracted from each other.")
+    };
+
+    let example = OperatorExample {
+        lhs: "[6, 8], [10, 12]",
+        rhs: "[5, 6], [7, 8]",
+        result: "[1, 2], [3, 4]",
+        panic: "the matrices do not have the same dimensions"
+    };
+
+    let documentation = matrix_documentation(
+        &data.lhs_ty,
+        matrix_binop_impl_doc("subtraction", "Sub", "-", &example, data.types_state())
+    );
+
+    matrix_elementwise_binop_impl(&op, &target, &data, documentation).into()
+}
+
+/// The lints allowed in a product implementation, since the products
+/// are summed with `+=`, which clippy would otherwise take for a mistaken
+/// operator in a `Mul` implementation.
+fn product_allowed_lints() -> proc_macro2::TokenStream {
+    quote!(#[allow(clippy::suspicious_arithmetic_impl)])
+}
+
+/// Generates the product of two matrices as `Mul` for the given types,
+/// e.g. `matrix_mul_impl!(&Matrix<T> &Matrix<T>)`, with the matrix type
+/// given the same way as in [matrix_add_impl!].
+/// 
+/// The product is accumulated in blocks of 64 by 64 elements,
+/// so the part of the right hand side in use stays in cache.
+/// A mutably borrowed side receives the product in place, and the product
+/// panics if the number of columns on the left differs from the number of
+/// rows on the right.
+#[proc_macro]
+pub fn matrix_mul_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorImplTypes);
+
+    let target = match matrix_target(&data) {
+        Ok(target) => target,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    let VectorTarget {
+        ty,
+        params,
+        predicates,
+        elem,
+        field,
+        construct,
+        ..
+    } = &target;
+
+    let lifetime = target.borrow_lifetime();
+    let (left_hand_type, right_hand_type) = binop_operand_types(&data, &lifetime, ty, ty);
+
+    let lhs_lengths = matrix_lengths(&target, quote!(self));
+    let rhs_lengths = matrix_lengths(&target, quote!(rhs));
+
+    let example = OperatorExample {
+        lhs: "[1, 2], [3, 4]",
+        rhs: "[5, 6], [7, 8]",
+        result: "[19, 22], [43, 50]",
+        panic: "the number of columns of the left hand side differs from the number of rows of the right hand side"
+    };
+
+    let documentation = matrix_documentation(
+        &data.lhs_ty,
+        matrix_binop_impl_doc("multiplication", "Mul", "*", &example, data.types_state())
+    );
+
+    // The product is accumulated tile by tile, so the rows of the
+    // right hand side used by a tile stay in cache while the
+    // corresponding rows of the result are updated.
+    let product = quote!{
+        const BLOCK_SIZE: usize = 64;
+
+        let (rows, inner) = #lhs_lengths;
+        let (rhs_rows, cols) = #rhs_lengths;
+
+        if inner != rhs_rows {
+            panic!(
+                "Cannot multiply a {}x{} matrix by a {}x{} matrix.",
+                rows, inner, rhs_rows, cols
+            )
+        }
+
+        let mut params = vec![vec![<#elem as Default>::default(); cols]; rows];
+
+        for row_block in (0..rows).step_by(BLOCK_SIZE) {
+            let row_end = usize::min(row_block + BLOCK_SIZE, rows);
+
+            for inner_block in (0..inner).step_by(BLOCK_SIZE) {
+                let inner_end = usize::min(inner_block + BLOCK_SIZE, inner);
+
+                for col_block in (0..cols).step_by(BLOCK_SIZE) {
+                    let col_end = usize::min(col_block + BLOCK_SIZE, cols);
+
+                    for row in row_block..row_end {
+                        let lhs_row = &self.#field[row];
+                        let params_row = &mut params[row][col_block..col_end];
+
+                        for idx in inner_block..inner_end {
+                            let lhs_value = lhs_row[idx];
+                            let rhs_row = &rhs.#field[idx][col_block..col_end];
+
+                            for (param, rhs_value) in params_row.iter_mut().zip(rhs_row) {
+                                *param += lhs_value * *rhs_value
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    let (generics, output, result) = match (is_mutable(&data.lhs_ty), is_mutable(&data.rhs_ty)) {
+        (true, _) => (
+            quote!(<#lifetime, #params>),
+            quote!(&#lifetime mut #ty),
+            quote!{
+                self.#field = params;
+                self
+            }
+        ),
+        (_, true) => (
+            quote!(<#lifetime, #params>),
+            quote!(&#lifetime mut #ty),
+            quote!{
+                rhs.#field = params;
+                rhs
+            }
+        ),
+        (false, false) => (
+            quote!(<#params>),
+            quote!(#ty),
+            quote!(#construct)
+        ),
+    };
+
+    let allowed_lints = product_allowed_lints();
+
+    let implementation = quote!{
+        #documentation
+        #allowed_lints
+        impl #generics Mul<#right_hand_type> for #left_hand_type
+        where
+            #elem: Copy + Mul<Output = #elem> + AddAssign + Default,
+            #(#predicates,)*
+        {
+            type Output = #output;
+
+            fn mul(self, rhs: #right_hand_type) -> Self::Output {
+                #product
+
+                #result
+            }
+        }
+    };
+
+    implementation.into()
+}
+
+/// The targets of the matrix and the vector of a matrix-vector product,
+/// e.g. `matrix: &Matrix<T>, vector: Vector<T>` in either order,
+/// which must have the same element type.
+fn matrix_vector_targets(input : &MatrixVectorMulImpl) -> syn::Result<(VectorTarget, VectorTarget)> {
+    let MatrixVectorMulImpl { types: data, matrix_on_left } = input;
+    let mut errors = Vec::new();
+
+    check_ownership(&data.lhs_ty, ANY_OWNERSHIP, "left hand side", &mut errors);
+    check_ownership(&data.rhs_ty, ANY_OWNERSHIP, "right hand side", &mut errors);
+    combine_errors(std::mem::take(&mut errors))?;
+
+    let (lhs_owned, rhs_owned) = (owned_type(&data.lhs_ty), owned_type(&data.rhs_ty));
+
+    let (matrix_ty, vector_ty) = if *matrix_on_left {
+        (lhs_owned, rhs_owned)
+    } else {
+        (rhs_owned, lhs_owned)
+    };
+
+    let matrix = VectorTarget::from_input(&data.generics, matrix_ty);
+    let vector = VectorTarget::from_input(&data.generics, vector_ty);
+
+    errors.extend(matrix.as_ref().err().cloned());
+    errors.extend(vector.as_ref().err().cloned());
+    combine_errors(std::mem::take(&mut errors))?;
+
+    let (mut matrix, vector) = (matrix?, vector?);
+
+    if matrix.elem.to_string() != vector.elem.to_string() {
+        errors.push(syn::Error::new_spanned(
+            vector_ty,
+            format!(
+                "expected the same element type as the matrix `{}`, found `{}`",
+                display_type(matrix_ty), display_type(vector_ty)
+            )
+        ));
+    }
+
+    for (target, ty, kind) in [(&matrix, matrix_ty, "matrices"), (&vector, vector_ty, "vectors")] {
+        if target.length.is_some() {
+            errors.push(syn::Error::new_spanned(
+                ty,
+                format!("fixed-size {} are not supported", kind)
+            ));
+        }
+    }
+
+    combine_errors(errors)?;
+
+    matrix.params = merge_params(&matrix.params, &vector.params)?;
+    Ok((matrix, vector))
+}
+
+/// Generates the product of a matrix and a vector as `Mul`, with each operand
+/// labelled by its role in the order of the product, e.g.
+/// `matrix_vector_mul_impl!(matrix: &Matrix<T>, vector: &Vector<T>)` for the
+/// product with a column vector and
+/// `matrix_vector_mul_impl!(vector: &Vector<T>, matrix: &Matrix<T>)` for the
+/// product with a row vector.
+/// 
+/// Both types must have the same element type, and a mutably borrowed vector
+/// receives the result in place. The product panics if the dimensions differ.
+/// The generics may be given in front of the operands, as described in the
+/// [crate documentation](crate#vector-types).
+#[proc_macro]
+pub fn matrix_vector_mul_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as MatrixVectorMulImpl);
+
+    let (matrix, vector) = match matrix_vector_targets(&input) {
+        Ok(targets) => targets,
+        Err(err) => return err.to_compile_error().into()
+    };
+
+    let MatrixVectorMulImpl { types: data, matrix_on_left } = input;
+
+    let VectorTarget {
+        params,
+        predicates,
+        elem,
+        ..
+    } = &matrix;
+
+    let (matrix_ty, vector_ty) = (&matrix.ty, &vector.ty);
+    let (field, vector_field, construct) = (&matrix.field, &vector.field, &vector.construct);
+
+    let lifetime = matrix.borrow_lifetime();
+    let (left_hand_type, right_hand_type) = if matrix_on_left {
+        binop_operand_types(&data, &lifetime, matrix_ty, vector_ty)
+    } else {
+        binop_operand_types(&data, &lifetime, vector_ty, matrix_ty)
+    };
+
+    // The examples are written for the upstream `Matrix<T>` and `Vector<T>`.
+    let (matrix_side, vector_side) = if matrix_on_left {
+        (&data.lhs_ty, &data.rhs_ty)
+    } else {
+        (&data.rhs_ty, &data.lhs_ty)
+    };
+    let documentation = matrix_documentation(
+        matrix_side,
+        vector_documentation(vector_side, matrix_vector_mul_impl_doc(matrix_on_left, data.types_state()))
+    );
+
+    let product = if matrix_on_left {
+        let lengths = matrix_lengths(&matrix, quote!(self));
+
+        quote!{
+            let (rows, cols) = #lengths;
+            let vector = &rhs.#vector_field;
+
+            if cols != vector.len() {
+                panic!(
+                    "Cannot multiply a {}x{} matrix by a vector of length {}.",
+                    rows, cols, vector.len()
+                )
+            }
+
+            let params: Vec<#elem> = self.#field.iter()
+                .map(|row| {
+                    let mut sum = <#elem as Default>::default();
+                    for (a, b) in row.iter().zip(vector.iter()) {
+                        sum += *a * *b
+                    }
+                    sum
+                })
+                .collect();
+        }
+    } else {
+        let lengths = matrix_lengths(&matrix, quote!(rhs));
+
+        // The rows of the matrix are scaled and summed,
+        // so the matrix is read in memory order.
+        quote!{
+            let (rows, cols) = #lengths;
+            let vector = &self.#vector_field;
+
+            if vector.len() != rows {
+                panic!(
+                    "Cannot multiply a vector of length {} by a {}x{} matrix.",
+                    vector.len(), rows, cols
+                )
+            }
+
+            let mut params = vec![<#elem as Default>::default(); cols];
+            for (value, row) in vector.iter().zip(rhs.#field.iter()) {
+                for (param, a) in params.iter_mut().zip(row.iter()) {
+                    *param += *value * *a
+                }
+            }
+        }
+    };
+
+    // Only a mutably borrowed vector receives the result,
+    // a mutably borrowed matrix is merely read.
+    let vector_is_mutable = if matrix_on_left {
+        is_mutable(&data.rhs_ty)
+    } else {
+        is_mutable(&data.lhs_ty)
+    };
+
+    let generics = if is_mutable(&data.lhs_ty) || is_mutable(&data.rhs_ty) {
+        quote!(<#lifetime, #params>)
+    } else {
+        quote!(<#params>)
+    };
+
+    let (output, result) = match (vector_is_mutable, matrix_on_left) {
+        (true, true) => (
+            quote!(&#lifetime mut #vector_ty),
+            quote!{
+                *rhs = #construct;
+                rhs
+            }
+        ),
+        (true, false) => (
+            quote!(&#lifetime mut #vector_ty),
+            quote!{
+                *self = #construct;
+                self
+            }
+        ),
+        (false, _) => (
+            quote!(#vector_ty),
+            quote!(#construct)
+        ),
+    };
+
+    let allowed_lints = product_allowed_lints();
+
+    let implementation = quote!{
+        #documentation
+        #allowed_lints
+        impl #generics Mul<#right_hand_type> for #left_hand_type
+        where
+            #elem: Copy + Mul<Output = #elem> + AddAssign + Default,
+            #(#predicates,)*
+        {
+            type Output = #output;
+
+            fn mul(self, rhs: #right_hand_type) -> Self::Output {
+                #product
+
+                #result
+            }
+        }
+    };
+
+    implementation.into()
+}
+
+/// Generates every supported ownership combination of the named
+/// operators in one invocation, e.g.
+/// 
+/// ```ignore
+/// vector_ops_impl!(Vector<T>: add, sub, dot, scalar_mul, neg);
+/// ```
+/// 
+/// which is the same as invoking [vector_add_impl!] and the other macros
+/// once for each combination of `&mut Vector<T>`, `&Vector<T>` and `Vector<T>`.
+/// The generics may be given in front of the type, as described in the
+/// [crate documentation](crate#vector-types).
+/// The length from which the operators run in parallel with the `parallel`
+/// feature may be given after them, e.g. `Vector<T>: add, sub, parallel_threshold = 4096`.
+/// 
+/// The supported operators are `add`, `sub`, `dot`, `dot_as`, `hadamard`,
+/// `elem_div`, `elem_rem`, `cross`, `try_add`, `try_sub`, `try_dot`,
+/// `scalar_mul`, `scalar_div`, `scalar_rem`, `neg`, `add_assign`,
+/// `sub_assign` and `scalar_mul_assign`.
+/// 
+/// # Note
+/// The combinations the individual macros reject are skipped:
+/// `add_assign` and `sub_assign` are only generated for
+/// `Vector<T> &Vector<T>` and `Vector<T> Vector<T>`,
+/// and `scalar_mul_assign` only for `Vector<T> T`.
+/// Every other operator is generated for all combinations.
+#[proc_macro]
+pub fn vector_ops_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let data = parse_macro_input!(input as VectorOpsImpl);
+
+    let mut errors = Vec::new();
+
+    check_ownership(&data.ty, &[Owned], "vector", &mut errors);
+
+    let target = VectorTarget::from_input(&data.generics, &data.ty).map(|target| VectorTarget {
+        parallel_threshold: data.parallel_threshold.unwrap_or(target.parallel_threshold),
+        ..target
+    });
+
+    let implementations: Vec<proc_macro2::TokenStream> = match &target {
+        Ok(target) => data.ops.iter()
+            .filter_map(|op| ops::operator_impls(op, target, true)
+                .map_err(|err| errors.push(err))
+                .ok())
+            .flatten()
+            .collect(),
+        Err(err) => {
+            errors.push(err.clone());
+            Vec::new()
+        }
+    };
+
+    if let Err(err) = combine_errors(errors) {
+        return err.to_compile_error().into()
+    }
+
+    quote!(#(#implementations)*).into()
+}
+
+/// Generates the vector operators for a struct wrapping a `Vec<T>`,
+/// slice-like or array field, e.g.
+/// 
+/// ```ignore
+/// #[derive(VectorOps)]
+/// #[vector_ops(field = "data", ops(add, sub, dot, scalar_mul))]
+/// struct Embedding<T> {
+///     data: Vec<T>,
+/// }
+/// ```
+/// 
+/// Each operator is implemented for every ownership combination,
+/// the same way as the function-like macros do for `Vector<T>`.
+/// The `field` may be omitted for structs with a single field.
+/// Any other fields must be `PhantomData` markers, which are filled
+/// with their default values, since the operators build new vectors
+/// from the elements alone.
+/// An array field such as `[T; N]` makes a fixed-size vector,
+/// whose operators do not check the lengths at runtime.
+/// 
+/// The scalar types listed in `scalars(..)`, e.g. `scalars(f32, f64)`,
+/// can multiply the vector from the left, as with [scalar_vector_mul_impl!].
+/// 
+/// The supported operators are the same as for [vector_ops_impl!].
+#[proc_macro_derive(VectorOps, attributes(vector_ops))]
+pub fn derive_vector_ops(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    derive::vector_ops(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
 }
\ No newline at end of file
@@ -0,0 +1,259 @@
+use quote::quote;
+use syn::{Ident, Type, parse_quote};
+
+use crate::{
+    ANY_OWNERSHIP, ASSIGN_LHS, ASSIGN_RHS, ElementwiseOp, Ownership, ScalarOp, Summation,
+    VectorImplType, VectorImplTypes, VectorTarget,
+    cross_prod_impl, dot_as_impl, dot_prod_impl, elementwise_assign_impl, elementwise_binop_impl,
+    is_borrow, is_mutable, neg_impl, scalar_binop_impl, scalar_mul_assign_impl,
+    try_dot_prod_impl, try_elementwise_binop_impl, vector_documentation
+};
+use crate::impl_docs::vector_add::vector_add_impl_doc;
+use crate::impl_docs::vector_sub::vector_sub_impl_doc;
+use crate::impl_docs::vector_elementwise::{MethodExample, vector_elementwise_impl_doc};
+use crate::impl_docs::vector_try_binop::vector_try_binop_impl_doc;
+use crate::impl_docs::vector_add_assign::vector_add_assign_impl_doc;
+use crate::impl_docs::vector_sub_assign::vector_sub_assign_impl_doc;
+use crate::impl_docs::vector_dot_prod::vector_dot_prod_impl_doc;
+use crate::impl_docs::vector_dot_as::vector_dot_as_impl_doc;
+use crate::impl_docs::vector_scalar_mul::vector_scalar_mul_impl_doc;
+use crate::impl_docs::vector_scalar_div::vector_scalar_div_impl_doc;
+use crate::impl_docs::vector_scalar_rem::vector_scalar_rem_impl_doc;
+use crate::impl_docs::vector_scalar_mul_assign::vector_scalar_mul_assign_impl_doc;
+use crate::impl_docs::vector_neg::vector_neg_impl_doc;
+
+/// The operators that can be generated by name.
+pub const SUPPORTED_OPS: &str =
+    "add, sub, dot, dot_as, hadamard, elem_div, elem_rem, cross, try_add, try_sub, try_dot, \
+     scalar_mul, scalar_div, scalar_rem, neg, add_assign, sub_assign, scalar_mul_assign";
+
+/// The ownerships the operands of an operator can be passed with,
+/// which are the ones accepted by the macro of the operator.
+enum Operands {
+    /// Two vectors, such as `&Vector<T> Vector<T>`.
+    Vectors(&'static [Ownership], &'static [Ownership]),
+    /// A vector and its element type, such as `&Vector<T> T`.
+    Scalar(&'static [Ownership]),
+    /// A single vector, such as `&Vector<T>`.
+    Vector(&'static [Ownership]),
+}
+
+fn operands(op : &str) -> Option<Operands> {
+    match op {
+        "add" | "sub" | "dot" | "dot_as" | "hadamard" | "elem_div" | "elem_rem" | "cross"
+        | "try_add" | "try_sub" | "try_dot" => Some(Operands::Vectors(ANY_OWNERSHIP, ANY_OWNERSHIP)),
+        "add_assign" | "sub_assign" => Some(Operands::Vectors(ASSIGN_LHS, ASSIGN_RHS)),
+        "scalar_mul" | "scalar_div" | "scalar_rem" => Some(Operands::Scalar(ANY_OWNERSHIP)),
+        "scalar_mul_assign" => Some(Operands::Scalar(ASSIGN_LHS)),
+        "neg" => Some(Operands::Vector(ANY_OWNERSHIP)),
+        _ => None
+    }
+}
+
+/// The implementation of the binary or scalar operator `op`
+/// for the types of `data`, which have been checked against its [Operands].
+///
+/// The documentation is only generated if `documented`,
+/// and only for the upstream `Vector`.
+pub fn vector_op_impl(
+    op : &str,
+    target : &VectorTarget,
+    data : &VectorImplTypes,
+    documented : bool
+) -> proc_macro2::TokenStream {
+    let types_state = data.types_state();
+    let vector_state = (is_borrow(&data.lhs_ty), is_mutable(&data.lhs_ty));
+
+    let document = |documentation| if documented {
+        vector_documentation(&data.lhs_ty, documentation)
+    } else {
+        quote!()
+    };
+
+    let same_size = "the vectors are not the same size";
+
+    match op {
+        "add" => elementwise_binop_impl(
+            &ElementwiseOp::add(), target, data,
+            document(vector_add_impl_doc(types_state))
+        ),
+        "sub" => elementwise_binop_impl(
+            &ElementwiseOp::sub(), target, data,
+            document(vector_sub_impl_doc(types_state))
+        ),
+        "hadamard" => {
+            let example = MethodExample { lhs: "1, 2, 3", rhs: "4, 5, 6", result: "4, 10, 18", panic: same_size };
+            elementwise_binop_impl(
+                &ElementwiseOp::hadamard(), target, data,
+                document(vector_elementwise_impl_doc("Hadamard", "hadamard", &example, types_state))
+            )
+        },
+        "elem_div" => {
+            let example = MethodExample { lhs: "4, 10, 18", rhs: "4, 5, 6", result: "1, 2, 3", panic: same_size };
+            elementwise_binop_impl(
+                &ElementwiseOp::elem_div(), target, data,
+                document(vector_elementwise_impl_doc("ElemDiv", "elem_div", &example, types_state))
+            )
+        },
+        "elem_rem" => {
+            let example = MethodExample { lhs: "4, 5, 6", rhs: "3, 3, 4", result: "1, 2, 2", panic: same_size };
+            elementwise_binop_impl(
+                &ElementwiseOp::elem_rem(), target, data,
+                document(vector_elementwise_impl_doc("ElemRem", "elem_rem", &example, types_state))
+            )
+        },
+        "cross" => {
+            let example = MethodExample {
+                lhs: "1, 2, 3",
+                rhs: "4, 5, 6",
+                result: "-3, 6, -3",
+                panic: "the vectors are not both of length 3 or both of length 7"
+            };
+            cross_prod_impl(
+                target, data,
+                document(vector_elementwise_impl_doc("Cross", "cross", &example, types_state))
+            )
+        },
+        "try_add" => {
+            let example = MethodExample { lhs: "1, 2, 3", rhs: "4, 5, 6", result: "5, 7, 9", panic: same_size };
+            try_elementwise_binop_impl(
+                &ElementwiseOp::add(), target, data,
+                document(vector_try_binop_impl_doc("TryAdd", "try_add", &example, true, types_state))
+            )
+        },
+        "try_sub" => {
+            let example = MethodExample { lhs: "5, 7, 9", rhs: "4, 5, 6", result: "1, 2, 3", panic: same_size };
+            try_elementwise_binop_impl(
+                &ElementwiseOp::sub(), target, data,
+                document(vector_try_binop_impl_doc("TrySub", "try_sub", &example, true, types_state))
+            )
+        },
+        "dot" | "try_dot" | "dot_as" => dot_op_impl(op, target, data, None, Summation::Naive, documented),
+        "add_assign" => elementwise_assign_impl(
+            &ElementwiseOp::add_assign(), target, data,
+            document(vector_add_assign_impl_doc(types_state))
+        ),
+        "sub_assign" => elementwise_assign_impl(
+            &ElementwiseOp::sub_assign(), target, data,
+            document(vector_sub_assign_impl_doc(types_state))
+        ),
+        "scalar_mul" => scalar_binop_impl(
+            &ScalarOp::mul(), target, data,
+            document(vector_scalar_mul_impl_doc(vector_state))
+        ),
+        "scalar_div" => scalar_binop_impl(
+            &ScalarOp::div(), target, data,
+            document(vector_scalar_div_impl_doc(vector_state))
+        ),
+        "scalar_rem" => scalar_binop_impl(
+            &ScalarOp::rem(), target, data,
+            document(vector_scalar_rem_impl_doc(vector_state))
+        ),
+        "scalar_mul_assign" => scalar_mul_assign_impl(
+            target,
+            document(vector_scalar_mul_assign_impl_doc(vector_state))
+        ),
+        _ => unreachable!()
+    }
+}
+
+/// The implementation of the dot product `op`, which is `dot`, `try_dot`
+/// or `dot_as`, summing the products with the `summation`
+/// in the `accumulator` type if one is given.
+/// 
+/// `dot_as` takes its accumulator type as a generic parameter instead.
+pub fn dot_op_impl(
+    op : &str,
+    target : &VectorTarget,
+    data : &VectorImplTypes,
+    accumulator : Option<&Type>,
+    summation : Summation,
+    documented : bool
+) -> proc_macro2::TokenStream {
+    let types_state = data.types_state();
+
+    let document = |documentation| if documented {
+        vector_documentation(&data.lhs_ty, documentation)
+    } else {
+        quote!()
+    };
+
+    match op {
+        "dot" => dot_prod_impl(
+            target, data, accumulator, summation,
+            document(vector_dot_prod_impl_doc(types_state))
+        ),
+        "try_dot" => {
+            let example = MethodExample {
+                lhs: "1, 2, 3",
+                rhs: "4, 5, 6",
+                result: "32",
+                panic: "the vectors are not the same size"
+            };
+            try_dot_prod_impl(
+                target, data, accumulator, summation,
+                document(vector_try_binop_impl_doc("TryDot", "try_dot", &example, false, types_state))
+            )
+        },
+        "dot_as" => dot_as_impl(
+            target, data, summation,
+            document(vector_dot_as_impl_doc(types_state))
+        ),
+        _ => unreachable!()
+    }
+}
+
+/// Every implementation of the operator `op` for the `target`,
+/// one for each ownership combination its macro accepts.
+/// The other combinations are skipped.
+pub fn operator_impls(
+    op : &Ident,
+    target : &VectorTarget,
+    documented : bool
+) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    let name = op.to_string();
+    let ty = &target.ty;
+    let elem = &target.elem;
+
+    let operands = operands(&name).ok_or_else(|| syn::Error::new_spanned(
+        op,
+        format!("unknown operator, expected one of: {}", SUPPORTED_OPS)
+    ))?;
+
+    let binop = |lhs_ty : Type, rhs_ty : Type| vector_op_impl(
+        &name,
+        target,
+        &VectorImplTypes {
+            generics: None,
+            lhs_ty: Box::new(lhs_ty),
+            rhs_ty: Box::new(rhs_ty),
+        },
+        documented
+    );
+
+    let implementations = match operands {
+        Operands::Vectors(lhs, rhs) => lhs.iter()
+            .flat_map(|lhs| rhs.iter().map(move |rhs| (lhs, rhs)))
+            .map(|(lhs, rhs)| binop(lhs.apply(ty), rhs.apply(ty)))
+            .collect(),
+        Operands::Scalar(allowed) => allowed.iter()
+            .map(|vector| binop(vector.apply(ty), parse_quote!(#elem)))
+            .collect(),
+        Operands::Vector(allowed) => allowed.iter()
+            .map(|vector| {
+                let data = VectorImplType {
+                    generics: None,
+                    ty: Box::new(vector.apply(ty)),
+                };
+                let documentation = if documented {
+                    vector_documentation(&data.ty, vector_neg_impl_doc((is_borrow(&data.ty), is_mutable(&data.ty))))
+                } else {
+                    quote!()
+                };
+                neg_impl(target, &data, documentation)
+            })
+            .collect(),
+    };
+
+    Ok(implementations)
+}
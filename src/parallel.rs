@@ -0,0 +1,156 @@
+use quote::quote;
+
+use crate::{Destination, FastPath, VectorTarget};
+
+/// The length from which the generated operators run in parallel,
+/// unless it is set with `parallel_threshold = ..`.
+pub const DEFAULT_THRESHOLD: usize = 1 << 16;
+
+/// Whether the target can take the parallel path.
+///
+/// Fixed-size vectors are expected to be too small to benefit,
+/// and both sides must have the same element type.
+fn enabled(target : &VectorTarget) -> bool {
+    cfg!(feature = "parallel") && target.length.is_none() && !target.is_mixed()
+}
+
+fn path(target : &VectorTarget, code : proc_macro2::TokenStream) -> FastPath {
+    let VectorTarget {
+        elem,
+        field,
+        parallel_threshold,
+        ..
+    } = target;
+
+    FastPath {
+        bound: quote!(#elem: Send + Sync,),
+        code: quote!{
+            if self.#field.len() >= #parallel_threshold {
+                use ::rayon::prelude::*;
+
+                #code
+            }
+        },
+    }
+}
+
+/// Runs `body` for each pair of elements `a` of `self` and `b` of `rhs`
+/// with `rayon`, then returns `result`.
+///
+/// For a new vector, `body` is the expression of each element,
+/// and otherwise it assigns the element of the destination.
+/// The vectors must already be known to be the same size.
+pub fn zip(
+    target : &VectorTarget,
+    body : proc_macro2::TokenStream,
+    destination : Destination,
+    result : proc_macro2::TokenStream
+) -> FastPath {
+    if !enabled(target) {
+        return FastPath::none()
+    }
+
+    let VectorTarget {
+        elem,
+        field,
+        ..
+    } = target;
+
+    let code = match destination {
+        Destination::New => quote!{
+            let params: Vec<#elem> = self.#field.par_iter()
+                .zip(rhs.#field.par_iter())
+                .map(|(a, b)| #body)
+                .collect();
+        },
+        Destination::Lhs => quote!{
+            self.#field.par_iter_mut()
+                .zip(rhs.#field.par_iter())
+                .for_each(|(a, b)| #body);
+        },
+        Destination::Rhs => quote!{
+            self.#field.par_iter()
+                .zip(rhs.#field.par_iter_mut())
+                .for_each(|(a, b)| #body);
+        },
+    };
+
+    path(target, quote!{
+        #code
+
+        return #result;
+    })
+}
+
+/// Runs `body` for each element `a` of `self` with `rayon`,
+/// then returns `result`.
+///
+/// For a new vector, `body` is the expression of each element,
+/// and otherwise it assigns the element.
+pub fn map(
+    target : &VectorTarget,
+    body : proc_macro2::TokenStream,
+    destination : Destination,
+    result : proc_macro2::TokenStream
+) -> FastPath {
+    if !enabled(target) {
+        return FastPath::none()
+    }
+
+    let VectorTarget {
+        elem,
+        field,
+        ..
+    } = target;
+
+    let code = match destination {
+        Destination::New => quote!{
+            let params: Vec<#elem> = self.#field.par_iter()
+                .map(|a| #body)
+                .collect();
+        },
+        Destination::Lhs => quote!{
+            self.#field.par_iter_mut().for_each(|a| #body);
+        },
+        Destination::Rhs => unreachable!(),
+    };
+
+    path(target, quote!{
+        #code
+
+        return #result;
+    })
+}
+
+/// Returns the dot product with a parallel reduction,
+/// in which each `rayon` job sums its own products.
+///
+/// The vectors must already be known to be the same size.
+pub fn dot(target : &VectorTarget, wrap : impl Fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> FastPath {
+    if !enabled(target) {
+        return FastPath::none()
+    }
+
+    let VectorTarget {
+        elem,
+        field,
+        ..
+    } = target;
+
+    let result = wrap(quote!(product));
+
+    path(target, quote!{
+        let product = self.#field.par_iter()
+            .zip(rhs.#field.par_iter())
+            .fold(<#elem>::default, |mut sum, (a, b)| {
+                sum += *a * *b;
+                sum
+            })
+            .reduce(<#elem>::default, |mut sum, partial| {
+                sum += partial;
+                sum
+            });
+
+        return #result;
+    })
+}
@@ -0,0 +1,214 @@
+use quote::{format_ident, quote};
+
+use crate::{Destination, FastPath, VectorTarget};
+
+/// The scalar float types with an explicit SIMD path, along with
+/// the AVX register, intrinsic suffix and number of elements per register.
+const FLOATS: [(&str, &str, &str, usize); 2] = [
+    ("f32", "__m256", "ps", 8),
+    ("f64", "__m256d", "pd", 4),
+];
+
+/// The intrinsic name of an elementwise operator, such as `add` for `+` and `+=`.
+fn intrinsic(operator : &proc_macro2::TokenStream) -> Option<&'static str> {
+    match operator.to_string().as_str() {
+        "+" | "+=" => Some("add"),
+        "-" | "-=" => Some("sub"),
+        "*" | "*=" => Some("mul"),
+        "/" | "/=" => Some("div"),
+        _ => None
+    }
+}
+
+/// The float type of the elements with its AVX register, intrinsic suffix
+/// and number of elements per register, if the target can take the explicit SIMD path.
+///
+/// Only element types written as `f32` or `f64`, such as in `Vector<f32>`,
+/// take the path, so generic implementations are left unchanged.
+/// Fixed-size vectors are left to the compiler,
+/// as their lengths are known at compile time,
+/// and both sides must have the same element type.
+fn float(target : &VectorTarget) -> Option<(&'static str, &'static str, &'static str, usize)> {
+    if !cfg!(feature = "simd") || target.length.is_some() || target.is_mixed() {
+        return None
+    }
+
+    let elem = target.elem.to_string();
+    FLOATS.iter().copied().find(|(float, ..)| *float == elem)
+}
+
+/// Runs an elementwise operator over `f32` and `f64` vectors with AVX
+/// when it is detected at runtime, then returns `result`.
+/// Otherwise the operator falls through to its scalar loop.
+///
+/// The vectors must already be known to be the same size.
+pub fn elementwise(
+    target : &VectorTarget,
+    operator : &proc_macro2::TokenStream,
+    destination : Destination,
+    result : proc_macro2::TokenStream
+) -> FastPath {
+    let (name, (float, register, suffix, width)) = match (intrinsic(operator), float(target)) {
+        (Some(name), Some(float)) => (name, float),
+        _ => return FastPath::none()
+    };
+
+    let field = &target.field;
+
+    let scalar_operator: proc_macro2::TokenStream = match name {
+        "add" => quote!(+),
+        "sub" => quote!(-),
+        "mul" => quote!(*),
+        _ => quote!(/),
+    };
+
+    let float = format_ident!("{}", float);
+    let register = format_ident!("{}", register);
+    let load = format_ident!("_mm256_loadu_{}", suffix);
+    let store = format_ident!("_mm256_storeu_{}", suffix);
+    let op = format_ident!("_mm256_{}_{}", name, suffix);
+
+    // In place, the destination is also passed as the operand it replaces,
+    // so that no shared pointer to it outlives the mutable one.
+    let (prepare, lhs, rhs, finish) = match destination {
+        Destination::New => (
+            quote!{
+                let mut params: Vec<#float> = Vec::with_capacity(length);
+                let out = params.as_mut_ptr();
+            },
+            quote!(self.#field.as_ptr()),
+            quote!(rhs.#field.as_ptr()),
+            // Safety: every element has been written by the kernel.
+            quote!(unsafe { params.set_len(length) }),
+        ),
+        Destination::Lhs => (
+            quote!(let out = self.#field.as_mut_ptr();),
+            quote!(out as *const #float),
+            quote!(rhs.#field.as_ptr()),
+            quote!(),
+        ),
+        Destination::Rhs => (
+            quote!(let out = rhs.#field.as_mut_ptr();),
+            quote!(self.#field.as_ptr()),
+            quote!(out as *const #float),
+            quote!(),
+        ),
+    };
+
+    let code = quote!{
+        #[cfg(target_arch = "x86_64")]
+        {
+            if ::std::is_x86_feature_detected!("avx") {
+                // The destination may be one of the operands,
+                // which is fine as each element is read before it is written.
+                #[target_feature(enable = "avx")]
+                unsafe fn kernel(a: *const #float, b: *const #float, out: *mut #float, length: usize) {
+                    use ::core::arch::x86_64::*;
+
+                    let mut idx = 0;
+                    while idx + #width <= length {
+                        unsafe {
+                            let x: #register = #load(a.add(idx));
+                            let y: #register = #load(b.add(idx));
+                            #store(out.add(idx), #op(x, y));
+                        }
+                        idx += #width;
+                    }
+
+                    while idx < length {
+                        unsafe { *out.add(idx) = *a.add(idx) #scalar_operator *b.add(idx) }
+                        idx += 1;
+                    }
+                }
+
+                let length = self.#field.len();
+                #prepare
+
+                // Safety: AVX is available, and both operands
+                // and the destination hold `length` elements.
+                unsafe { kernel(#lhs, #rhs, out, length) }
+                #finish
+
+                return #result;
+            }
+        }
+    };
+
+    FastPath {
+        bound: quote!(),
+        code,
+    }
+}
+
+/// Returns the dot product of `f32` and `f64` vectors, computed with
+/// four AVX accumulators when AVX is detected at runtime.
+/// Otherwise the dot product falls through to its scalar loop.
+///
+/// The vectors must already be known to be the same size.
+pub fn dot(target : &VectorTarget, wrap : impl Fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> FastPath {
+    let (float, register, suffix, width) = match float(target) {
+        Some(float) => float,
+        None => return FastPath::none()
+    };
+
+    let field = &target.field;
+
+    let float = format_ident!("{}", float);
+    let register = format_ident!("{}", register);
+    let load = format_ident!("_mm256_loadu_{}", suffix);
+    let store = format_ident!("_mm256_storeu_{}", suffix);
+    let add = format_ident!("_mm256_add_{}", suffix);
+    let mul = format_ident!("_mm256_mul_{}", suffix);
+    let setzero = format_ident!("_mm256_setzero_{}", suffix);
+    let step = width * 4;
+
+    // Safety: AVX is available.
+    let result = wrap(quote!(unsafe { kernel(&self.#field, &rhs.#field) }));
+
+    let code = quote!{
+        #[cfg(target_arch = "x86_64")]
+        {
+            if ::std::is_x86_feature_detected!("avx") {
+                #[target_feature(enable = "avx")]
+                unsafe fn kernel(a: &[#float], b: &[#float]) -> #float {
+                    use ::core::arch::x86_64::*;
+
+                    let length = a.len().min(b.len());
+                    let (a, b) = (a.as_ptr(), b.as_ptr());
+
+                    let mut acc: [#register; 4] = [#setzero(); 4];
+                    let mut idx = 0;
+                    while idx + #step <= length {
+                        for (lane, acc) in acc.iter_mut().enumerate() {
+                            let offset = idx + lane * #width;
+                            unsafe {
+                                let x: #register = #load(a.add(offset));
+                                let y: #register = #load(b.add(offset));
+                                *acc = #add(*acc, #mul(x, y));
+                            }
+                        }
+                        idx += #step;
+                    }
+
+                    let sum = #add(#add(acc[0], acc[1]), #add(acc[2], acc[3]));
+                    let mut lanes = [0.0 as #float; #width];
+                    unsafe { #store(lanes.as_mut_ptr(), sum) };
+
+                    let mut product: #float = lanes.iter().sum();
+                    while idx < length {
+                        unsafe { product += *a.add(idx) * *b.add(idx) }
+                        idx += 1;
+                    }
+                    product
+                }
+
+                return #result;
+            }
+        }
+    };
+
+    FastPath {
+        bound: quote!(),
+        code,
+    }
+}
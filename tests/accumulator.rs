@@ -0,0 +1,50 @@
+use std::ops::*;
+
+use simp_linalg_proc_macro::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vector<T> {
+    list: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(list: Vec<T>) -> Self {
+        Vector { list }
+    }
+}
+
+vector_traits!();
+
+vector_dot_prod_impl!(&Vector<i8> &Vector<i8> => i32);
+vector_dot_prod_impl!(&Vector<u8> &Vector<u8> => u32);
+vector_dot_as_impl!(&Vector<T> &Vector<T>);
+
+#[test]
+fn quantized_dot_products_do_not_overflow() {
+    // Every product, and their sum, is far out of the range of `i8`.
+    let a = Vector::from(vec![127i8; 64]);
+    let b = Vector::from(vec![-128i8; 64]);
+
+    let product: i32 = &a * &b;
+    assert_eq!(product, 127 * -128 * 64);
+
+    let c = Vector::from(vec![255u8; 512]);
+    let product: u32 = &c * &c;
+    assert_eq!(product, 255 * 255 * 512);
+}
+
+#[test]
+fn dot_as_sums_in_the_requested_type() {
+    let a = Vector::from(vec![100i8, -100, 50]);
+    let b = Vector::from(vec![100i8, -100, -2]);
+
+    assert_eq!((&a).dot_as::<i32>(&b), 19900);
+    assert_eq!((&a).dot_as::<i64>(&b), 19900);
+    assert_eq!((&a).dot_as::<f64>(&b), 19900.0);
+}
+
+#[test]
+#[should_panic(expected = "Cannot find dot product of two differently sized vectors.")]
+fn widened_dot_products_check_the_size() {
+    let _: i32 = &Vector::from(vec![1i8, 2]) * &Vector::from(vec![1i8]);
+}
@@ -0,0 +1,53 @@
+use std::ops::*;
+
+use simp_linalg_proc_macro::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vector<T> {
+    list: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(list: Vec<T>) -> Self {
+        Vector { list }
+    }
+}
+
+vector_add_assign_impl!(Vector<T> &Vector<T>);
+vector_add_assign_impl!(Vector<T> Vector<T>);
+vector_sub_assign_impl!(Vector<T> &Vector<T>);
+vector_sub_assign_impl!(Vector<T> Vector<T>);
+vector_scalar_mul_assign_impl!(Vector<T> T);
+
+#[test]
+fn compound_assignments() {
+    let mut v = Vector::from(vec![1.0, 2.0, 3.0]);
+    let w = Vector::from(vec![0.5, 0.25, 0.125]);
+
+    v += &w;
+    assert_eq!(v, Vector::from(vec![1.5, 2.25, 3.125]));
+
+    v -= w.clone();
+    assert_eq!(v, Vector::from(vec![1.0, 2.0, 3.0]));
+
+    v *= 2.0;
+    assert_eq!(v, Vector::from(vec![2.0, 4.0, 6.0]));
+
+    v -= &w;
+    v += w;
+    assert_eq!(v, Vector::from(vec![2.0, 4.0, 6.0]));
+}
+
+#[test]
+#[should_panic(expected = "Vectors with different sizes cannot be added together.")]
+fn add_assign_checks_the_size() {
+    let mut v = Vector::from(vec![1, 2, 3]);
+    v += &Vector::from(vec![1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "Vectors with different sizes cannot be subtracted from each other.")]
+fn sub_assign_checks_the_size() {
+    let mut v = Vector::from(vec![1, 2, 3]);
+    v -= Vector::from(vec![1, 2, 3, 4]);
+}
@@ -0,0 +1,48 @@
+use std::ops::*;
+
+use simp_linalg_proc_macro::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vector<T> {
+    list: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(list: Vec<T>) -> Self {
+        Vector { list }
+    }
+}
+
+vector_binop_impl!(Sub, sub, -; &Vector<T> &Vector<T>);
+vector_binop_impl!(Sub, sub, -; &Vector<T> &mut Vector<T>);
+vector_binop_impl!(Sub, sub, -; Vector<T> &Vector<T>);
+vector_binop_impl!(BitAnd, bitand, &; &Vector<T> &Vector<T>);
+vector_binop_impl!(BitXor, bitxor, ^; &mut Vector<T> &Vector<T>);
+vector_binop_impl!(Shl, shl, <<; &Vector<T> Vector<T>);
+
+#[test]
+fn any_operator_in_every_ownership() {
+    let a = Vector::from(vec![12, 10, 6]);
+    let b = Vector::from(vec![3, 6, 5]);
+
+    assert_eq!(&a - &b, Vector::from(vec![9, 4, 1]));
+    assert_eq!(a.clone() - &b, Vector::from(vec![9, 4, 1]));
+
+    let mut rhs = b.clone();
+    let _ = &a - &mut rhs;
+    assert_eq!(rhs, Vector::from(vec![9, 4, 1]));
+
+    assert_eq!(&a & &b, Vector::from(vec![0, 2, 4]));
+
+    let mut lhs = a.clone();
+    let _ = &mut lhs ^ &b;
+    assert_eq!(lhs, Vector::from(vec![15, 12, 3]));
+
+    assert_eq!(&b << Vector::from(vec![1, 2, 3]), Vector::from(vec![6, 24, 40]));
+}
+
+#[test]
+#[should_panic(expected = "Vectors with different sizes cannot be combined with '&'.")]
+fn the_mismatch_names_the_operator() {
+    let _ = &Vector::from(vec![1, 2, 3]) & &Vector::from(vec![1, 2]);
+}
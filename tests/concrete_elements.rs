@@ -0,0 +1,47 @@
+use std::ops::*;
+use std::time::Duration;
+
+use simp_linalg_proc_macro::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vector<T> {
+    list: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(list: Vec<T>) -> Self {
+        Vector { list }
+    }
+}
+
+// A concrete element type which is not a primitive is given with `impl<>`,
+// so these impls do not overlap with the ones for `f32`.
+vector_add_impl!(impl<> &Vector<Duration> &Vector<Duration>);
+vector_sub_impl!(impl<> Vector<Duration> &Vector<Duration>);
+
+vector_add_impl!(&Vector<f32> &Vector<f32>);
+vector_sub_impl!(Vector<f32> &Vector<f32>);
+
+#[test]
+fn concrete_non_primitive_elements() {
+    let a = Vector::from(vec![Duration::from_secs(1), Duration::from_millis(500)]);
+    let b = Vector::from(vec![Duration::from_secs(2), Duration::from_millis(750)]);
+
+    assert_eq!(
+        &a + &b,
+        Vector::from(vec![Duration::from_secs(3), Duration::from_millis(1250)])
+    );
+    assert_eq!(
+        b.clone() - &a,
+        Vector::from(vec![Duration::from_secs(1), Duration::from_millis(250)])
+    );
+}
+
+#[test]
+fn primitive_elements_next_to_concrete_ones() {
+    let a = Vector::from(vec![1.0f32, 2.0]);
+    let b = Vector::from(vec![0.5f32, 0.25]);
+
+    assert_eq!(&a + &b, Vector::from(vec![1.5, 2.25]));
+    assert_eq!(a - &b, Vector::from(vec![0.5, 1.75]));
+}
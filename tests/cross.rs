@@ -0,0 +1,87 @@
+use std::ops::*;
+
+use simp_linalg_proc_macro::*;
+
+vector_traits!();
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vector<T> {
+    list: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(list: Vec<T>) -> Self {
+        Vector { list }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fixed<T, const N: usize> {
+    list: [T; N],
+}
+
+impl<T, const N: usize> From<[T; N]> for Fixed<T, N> {
+    fn from(list: [T; N]) -> Self {
+        Fixed { list }
+    }
+}
+
+#[derive(VectorOps, Debug, Clone, Copy, PartialEq)]
+#[vector_ops(ops(cross))]
+pub struct Triple([i32; 3]);
+
+vector_cross_prod_impl!(&Vector<T> &Vector<T>);
+vector_cross_prod_impl!(impl<T, const N: usize> &Fixed<T, N> &Fixed<T, N>);
+vector_cross_prod_impl!(impl<T, const N: usize> &mut Fixed<T, N> &Fixed<T, N>);
+
+#[test]
+fn cross_product_of_growable_vectors() {
+    let a = Vector::from(vec![1, 2, 3]);
+    let b = Vector::from(vec![4, 5, 6]);
+
+    assert_eq!((&a).cross(&b), Vector::from(vec![-3, 6, -3]));
+}
+
+#[test]
+#[should_panic(expected = "The cross product is only defined for two vectors of length 3 or 7.")]
+fn cross_product_of_growable_vectors_checks_the_lengths() {
+    let a = Vector::from(vec![1, 2, 3, 4]);
+    let b = Vector::from(vec![4, 5, 6, 7]);
+
+    let _ = (&a).cross(&b);
+}
+
+#[test]
+fn cross_product_of_fixed_size_vectors() {
+    let a = Fixed::from([1, 2, 3]);
+    let b = Fixed::from([4, 5, 6]);
+    assert_eq!((&a).cross(&b), Fixed::from([-3, 6, -3]));
+
+    let mut c = a;
+    let _ = (&mut c).cross(&b);
+    assert_eq!(c, Fixed::from([-3, 6, -3]));
+
+    assert_eq!((&Triple([1, 2, 3])).cross(&Triple([4, 5, 6])), Triple([-3, 6, -3]));
+}
+
+#[test]
+fn seven_dimensional_cross_product_matches_growable_vectors() {
+    let a = [1, 2, 3, 4, 5, 6, 7];
+    let b = [7, -6, 5, -4, 3, -2, 1];
+
+    let fixed = (&Fixed::from(a)).cross(&Fixed::from(b));
+    let growable = (&Vector::from(a.to_vec())).cross(&Vector::from(b.to_vec()));
+
+    assert_eq!(fixed.list.to_vec(), growable.list);
+
+    let dot = |x: &[i32], y: &[i32]| x.iter().zip(y).map(|(x, y)| x * y).sum::<i32>();
+
+    // The product is orthogonal to both operands and satisfies
+    // the Lagrange identity |a × b|² = |a|²|b|² - (a · b)².
+    assert_eq!(dot(&fixed.list, &a), 0);
+    assert_eq!(dot(&fixed.list, &b), 0);
+    assert_eq!(
+        dot(&fixed.list, &fixed.list),
+        dot(&a, &a) * dot(&b, &b) - dot(&a, &b).pow(2)
+    );
+}
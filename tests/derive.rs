@@ -0,0 +1,46 @@
+use std::marker::PhantomData;
+
+use simp_linalg_proc_macro::VectorOps;
+
+/// A unit marker, which the derived operators fill with its default value.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Meters;
+
+#[derive(VectorOps, Debug, Clone, PartialEq)]
+#[vector_ops(field = "data", ops(add, sub, scalar_mul, neg), scalars(f32))]
+pub struct Displacement<T = f32, U = Meters> {
+    data: Vec<T>,
+    unit: PhantomData<U>,
+}
+
+fn displacement(data: Vec<f32>) -> Displacement {
+    Displacement { data, unit: PhantomData }
+}
+
+#[test]
+fn defaulted_generic_parameters() {
+    let a = displacement(vec![1.0, 2.0]);
+    let b = displacement(vec![0.5, 0.5]);
+
+    assert_eq!(&a + &b, displacement(vec![1.5, 2.5]));
+    assert_eq!(&a - &b, displacement(vec![0.5, 1.5]));
+    assert_eq!(&a * 2.0, displacement(vec![2.0, 4.0]));
+    assert_eq!(2.0 * &a, displacement(vec![2.0, 4.0]));
+    assert_eq!(-&a, displacement(vec![-1.0, -2.0]));
+}
+
+#[test]
+fn result_does_not_depend_on_ownership() {
+    let a = displacement(vec![1.0, 2.0]);
+    let b = displacement(vec![3.0, 5.0]);
+
+    let borrowed = &a + &b;
+
+    assert_eq!(a.clone() + &b, borrowed);
+    assert_eq!(&a + b.clone(), borrowed);
+    assert_eq!(a.clone() + b.clone(), borrowed);
+
+    let mut mutated = a.clone();
+    let _ = &mut mutated + &b;
+    assert_eq!(mutated, borrowed);
+}
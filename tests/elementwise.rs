@@ -0,0 +1,60 @@
+use std::ops::*;
+
+use simp_linalg_proc_macro::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vector<T> {
+    list: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(list: Vec<T>) -> Self {
+        Vector { list }
+    }
+}
+
+vector_traits!();
+
+vector_hadamard_impl!(&Vector<T> &Vector<T>);
+vector_hadamard_impl!(&mut Vector<T> &Vector<T>);
+vector_hadamard_impl!(Vector<T> Vector<T>);
+vector_elem_div_impl!(&Vector<T> &Vector<T>);
+vector_elem_div_impl!(&Vector<T> &mut Vector<T>);
+vector_elem_rem_impl!(&Vector<T> &Vector<T>);
+vector_elem_rem_impl!(Vector<T> &Vector<T>);
+
+#[test]
+fn elementwise_products_quotients_and_remainders() {
+    let a = Vector::from(vec![4, 10, 18]);
+    let b = Vector::from(vec![4, 5, 6]);
+
+    assert_eq!((&a).hadamard(&b), Vector::from(vec![16, 50, 108]));
+    assert_eq!(a.clone().hadamard(b.clone()), Vector::from(vec![16, 50, 108]));
+    assert_eq!((&a).elem_div(&b), Vector::from(vec![1, 2, 3]));
+    assert_eq!((&a).elem_rem(&Vector::from(vec![3, 3, 4])), Vector::from(vec![1, 1, 2]));
+    assert_eq!(a.clone().elem_rem(&Vector::from(vec![3, 3, 4])), Vector::from(vec![1, 1, 2]));
+}
+
+#[test]
+fn in_place_variants_write_to_the_mutable_side() {
+    let mut lhs = Vector::from(vec![1, 2, 3]);
+    (&mut lhs).hadamard(&Vector::from(vec![4, 5, 6]));
+    assert_eq!(lhs, Vector::from(vec![4, 10, 18]));
+
+    let mut rhs = Vector::from(vec![4, 5, 6]);
+    let quotient = (&lhs).elem_div(&mut rhs);
+    assert_eq!(*quotient, Vector::from(vec![1, 2, 3]));
+    assert_eq!(rhs, Vector::from(vec![1, 2, 3]));
+}
+
+#[test]
+#[should_panic(expected = "Cannot find the Hadamard product of two differently sized vectors.")]
+fn hadamard_checks_the_size() {
+    (&Vector::from(vec![1, 2, 3])).hadamard(&Vector::from(vec![1, 2]));
+}
+
+#[test]
+#[should_panic(expected = "Vectors with different sizes cannot be divided elementwise.")]
+fn elem_div_checks_the_size() {
+    (&Vector::from(vec![1, 2])).elem_div(&Vector::from(vec![1, 2, 3]));
+}
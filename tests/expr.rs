@@ -0,0 +1,199 @@
+use std::ops::*;
+
+use simp_linalg_proc_macro::*;
+
+vector_expr_traits!();
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lazy<T> {
+    list: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for Lazy<T> {
+    fn from(list: Vec<T>) -> Self {
+        Lazy { list }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LazyFixed<T, const N: usize> {
+    list: [T; N],
+}
+
+impl<T, const N: usize> From<[T; N]> for LazyFixed<T, N> {
+    fn from(list: [T; N]) -> Self {
+        LazyFixed { list }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Eager<T> {
+    list: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for Eager<T> {
+    fn from(list: Vec<T>) -> Self {
+        Eager { list }
+    }
+}
+
+vector_expr_impl!(Lazy<T>);
+vector_expr_impl!(impl<T, const N: usize> LazyFixed<T, N>);
+vector_ops_impl!(Eager<T>: add, sub, scalar_mul);
+
+#[test]
+fn lazy_expressions_match_the_eager_operators() {
+    let (a, b, c) = (vec![1, 2, 3, 4], vec![5, -6, 7, 8], vec![9, 10, -11, 12]);
+    let s = 3;
+
+    let eager = &Eager::from(a.clone()) + &Eager::from(b.clone()) * s + &Eager::from(c.clone());
+    let eager_difference = &Eager::from(a.clone()) - &Eager::from(c.clone()) * s;
+
+    let (a, b, c) = (Lazy::from(a), Lazy::from(b), Lazy::from(c));
+
+    let evaluated = (&a + &b * s + &c).eval();
+    assert_eq!(evaluated.list, eager.list);
+    assert_eq!((&a - &c * s).eval().list, eager_difference.list);
+
+    let mut assigned = Lazy::from(vec![0; 4]);
+    (&a + &b * s + &c).eval_into(&mut assigned);
+    assert_eq!(assigned.list, eager.list);
+
+    assert!(evaluated == &a + &b * s + &c);
+    assert!(&a + &b * s + &c == evaluated);
+    assert!(evaluated != &a + &b + &c);
+}
+
+#[test]
+fn lazy_expressions_of_fixed_size_vectors() {
+    let a = LazyFixed::from([1.0, 2.0, 3.0]);
+    let b = LazyFixed::from([0.5, 0.25, 0.125]);
+
+    assert_eq!((&a + &b * 2.0).eval(), LazyFixed::from([2.0, 2.5, 3.25]));
+    assert!((&a - &a).eval() == LazyFixed::from([0.0; 3]));
+}
+
+#[test]
+#[should_panic(expected = "The index 3 is out of bounds.")]
+fn at_checks_the_index() {
+    let a = Lazy::from(vec![1, 2, 3]);
+    let expr = &a + &a;
+
+    assert_eq!(expr.at(2), 6);
+    let _ = expr.at(3);
+}
+
+#[test]
+#[should_panic(expected = "Vectors with different sizes cannot be added together.")]
+fn lazy_expressions_check_the_sizes() {
+    let a = Lazy::from(vec![1, 2, 3]);
+    let b = Lazy::from(vec![1, 2]);
+
+    let _ = &a + &b;
+}
+
+#[test]
+#[should_panic(expected = "Vectors with different sizes cannot be assigned to each other.")]
+fn eval_into_checks_the_size() {
+    let a = Lazy::from(vec![1, 2, 3]);
+    let mut b = Lazy::from(vec![0; 2]);
+
+    (&a + &a).eval_into(&mut b);
+}
+
+/// An expression implemented outside the macros, which keeps to the contract of `VectorExpr`.
+struct Ramp(usize);
+
+unsafe impl VectorExpr for Ramp {
+    type Elem = i32;
+    type Vector = Lazy<i32>;
+
+    fn len(&self) -> usize {
+        self.0
+    }
+
+    unsafe fn at_unchecked(&self, idx: usize) -> Self::Elem {
+        idx as i32
+    }
+}
+
+#[test]
+fn custom_expressions_combine_with_the_nodes() {
+    let a = Lazy::from(vec![10, 20, 30]);
+
+    assert_eq!(AddExpr::new(&a, Ramp(3)).eval().list, vec![10, 21, 32]);
+    assert_eq!(Ramp(3).at(2), 2);
+}
+
+#[test]
+#[should_panic(expected = "The index 3 is out of bounds.")]
+fn checked_reads_stop_at_the_length() {
+    Ramp(3).at(3);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Counts<T> {
+    list: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for Counts<T> {
+    fn from(list: Vec<T>) -> Self {
+        Counts { list }
+    }
+}
+
+vector_expr_impl!(impl<> Counts<u8>);
+
+#[test]
+fn concrete_elements_without_generics() {
+    let (a, b) = (Counts::from(vec![1u8, 2, 3]), Counts::from(vec![4u8, 5, 6]));
+
+    assert_eq!((&a + &b * 2).eval().list, vec![9, 12, 15]);
+    assert!(Counts::from(vec![3, 3, 3]) == &b - &a);
+}
+
+/// The affine map `x -> scale * x + shift`, added pointwise
+/// and multiplied by composition, which does not commute.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Affine {
+    scale: i32,
+    shift: i32,
+}
+
+impl Mul for Affine {
+    type Output = Affine;
+
+    fn mul(self, rhs: Affine) -> Affine {
+        Affine {
+            scale: self.scale * rhs.scale,
+            shift: self.scale * rhs.shift + self.shift,
+        }
+    }
+}
+
+impl Add for Affine {
+    type Output = Affine;
+
+    fn add(self, rhs: Affine) -> Affine {
+        Affine { scale: self.scale + rhs.scale, shift: self.shift + rhs.shift }
+    }
+}
+
+impl Sub for Affine {
+    type Output = Affine;
+
+    fn sub(self, rhs: Affine) -> Affine {
+        Affine { scale: self.scale - rhs.scale, shift: self.shift - rhs.shift }
+    }
+}
+
+vector_expr_impl!(impl<> Counts<Affine>);
+
+#[test]
+fn scaling_keeps_the_scalar_as_the_left_factor() {
+    let maps = vec![Affine { scale: 2, shift: 1 }, Affine { scale: 1, shift: -3 }];
+    let scalar = Affine { scale: 3, shift: 5 };
+    let expected: Vec<Affine> = maps.iter().map(|map| scalar * *map).collect();
+
+    assert_eq!((&Counts::from(maps) * scalar).eval().list, expected);
+}
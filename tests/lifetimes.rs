@@ -0,0 +1,49 @@
+use std::ops::*;
+
+use simp_linalg_proc_macro::*;
+
+/// A vector type declaring the same lifetime name as the generated borrows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tagged<'a, T> {
+    list: Vec<T>,
+    tag: &'a str,
+}
+
+impl<'a, T> From<Vec<T>> for Tagged<'a, T> {
+    fn from(list: Vec<T>) -> Self {
+        Tagged { list, tag: "" }
+    }
+}
+
+vector_traits!();
+
+vector_add_impl!(&mut Tagged<'a, T> &Tagged<'a, T>);
+vector_sub_impl!(&Tagged<'a, T> &mut Tagged<'a, T>);
+vector_dot_prod_impl!(&mut Tagged<'a, T> &Tagged<'a, T>);
+vector_scalar_mul_impl!(&mut Tagged<'a, T> T);
+vector_neg_impl!(&mut Tagged<'a, T>);
+vector_ops_impl!(Tagged<'a, T>: hadamard, cross);
+
+#[test]
+fn borrows_next_to_a_declared_lifetime() {
+    let tag = String::from("velocity");
+    let mut a = Tagged { list: vec![1, 2, 3], tag: &tag };
+    let b = Tagged::from(vec![4, 5, 6]);
+
+    assert_eq!(&mut a * &b, 32);
+
+    let _ = &mut a + &b;
+    assert_eq!(a.list, vec![5, 7, 9]);
+    assert_eq!(a.tag, "velocity");
+
+    let mut c = Tagged::from(vec![1, 1, 1]);
+    let _ = &b - &mut c;
+    assert_eq!(c.list, vec![3, 4, 5]);
+
+    let _ = &mut c * 2;
+    let _ = -&mut c;
+    assert_eq!(c.list, vec![-6, -8, -10]);
+
+    assert_eq!((&b).hadamard(&c).list, vec![-24, -40, -60]);
+    assert_eq!((&b).cross(&c).list, vec![-2, 4, -2]);
+}
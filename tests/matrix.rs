@@ -0,0 +1,81 @@
+use std::ops::*;
+
+use simp_linalg_proc_macro::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<T> {
+    list: Vec<Vec<T>>,
+}
+
+impl<T> From<Vec<Vec<T>>> for Grid<T> {
+    fn from(list: Vec<Vec<T>>) -> Self {
+        Grid { list }
+    }
+}
+
+/// A vector type unrelated to the name of the matrix,
+/// told apart only by its role in the invocation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Embedding<T> {
+    list: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for Embedding<T> {
+    fn from(list: Vec<T>) -> Self {
+        Embedding { list }
+    }
+}
+
+matrix_add_impl!(&Grid<T> &Grid<T>);
+matrix_add_impl!(Grid<T> &Grid<T>);
+matrix_sub_impl!(&mut Grid<T> &Grid<T>);
+matrix_mul_impl!(&Grid<T> &Grid<T>);
+matrix_mul_impl!(&Grid<T> &mut Grid<T>);
+matrix_vector_mul_impl!(matrix: &Grid<T>, vector: &Embedding<T>);
+matrix_vector_mul_impl!(vector: &mut Embedding<T>, matrix: &Grid<T>);
+
+fn grid(rows: &[[i32; 2]]) -> Grid<i32> {
+    Grid::from(rows.iter().map(|row| row.to_vec()).collect::<Vec<_>>())
+}
+
+#[test]
+fn elementwise_operators_on_a_custom_matrix() {
+    let a = grid(&[[1, 2], [3, 4]]);
+    let b = grid(&[[5, 6], [7, 8]]);
+
+    assert_eq!(&a + &b, grid(&[[6, 8], [10, 12]]));
+    assert_eq!(a.clone() + &b, grid(&[[6, 8], [10, 12]]));
+
+    let mut c = b.clone();
+    let _ = &mut c - &a;
+    assert_eq!(c, grid(&[[4, 4], [4, 4]]));
+}
+
+#[test]
+fn products_on_a_custom_matrix() {
+    let a = grid(&[[1, 2], [3, 4]]);
+    let mut b = grid(&[[5, 6], [7, 8]]);
+
+    assert_eq!(&a * &b, grid(&[[19, 22], [43, 50]]));
+
+    let _ = &a * &mut b;
+    assert_eq!(b, grid(&[[19, 22], [43, 50]]));
+
+    assert_eq!(&a * &Embedding::from(vec![5, 6]), Embedding::from(vec![17, 39]));
+
+    let mut v = Embedding::from(vec![5, 6]);
+    let _ = &mut v * &a;
+    assert_eq!(v, Embedding::from(vec![23, 34]));
+}
+
+#[test]
+#[should_panic(expected = "Matrices with different dimensions cannot be added together.")]
+fn elementwise_operators_check_the_dimensions() {
+    let _ = &grid(&[[1, 2]]) + &grid(&[[1, 2], [3, 4]]);
+}
+
+#[test]
+#[should_panic(expected = "Cannot multiply a 2x2 matrix by a vector of length 3.")]
+fn products_check_the_dimensions() {
+    let _ = &grid(&[[1, 2], [3, 4]]) * &Embedding::from(vec![1, 2, 3]);
+}
@@ -0,0 +1,61 @@
+use std::ops::*;
+use std::time::{Duration, Instant};
+
+use simp_linalg_proc_macro::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vector<T> {
+    list: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(list: Vec<T>) -> Self {
+        Vector { list }
+    }
+}
+
+vector_add_impl!(&Vector<T> &Vector<U>);
+vector_add_impl!(&mut Vector<T> &Vector<U>);
+vector_add_impl!(Vector<T> Vector<U>);
+vector_sub_impl!(&Vector<T> &Vector<U>);
+vector_dot_prod_impl!(&Vector<T> &Vector<U>);
+vector_add_assign_impl!(Vector<T> &Vector<U>);
+
+#[test]
+fn instants_shifted_by_durations() {
+    let start = Instant::now();
+    let instants = Vector::from(vec![start, start + Duration::from_secs(1)]);
+    let delays = Vector::from(vec![Duration::from_millis(250), Duration::from_secs(2)]);
+
+    let shifted: Vector<Instant> = &instants + &delays;
+    assert_eq!(shifted.list, vec![start + Duration::from_millis(250), start + Duration::from_secs(3)]);
+
+    let elapsed: Vector<Duration> = &shifted - &instants;
+    assert_eq!(elapsed, delays);
+
+    let mut in_place = instants.clone();
+    let _ = &mut in_place + &delays;
+    assert_eq!(in_place, shifted);
+
+    let mut assigned = instants.clone();
+    assigned += &delays;
+    assert_eq!(assigned, shifted);
+
+    assert_eq!(instants + delays, shifted);
+}
+
+#[test]
+fn durations_scaled_by_counts() {
+    let durations = Vector::from(vec![Duration::from_millis(10), Duration::from_millis(20)]);
+    let counts = Vector::from(vec![3u32, 4]);
+
+    let total: Duration = &durations * &counts;
+    assert_eq!(total, Duration::from_millis(110));
+}
+
+#[test]
+#[should_panic(expected = "Vectors with different sizes cannot be added together.")]
+fn mixed_elements_check_the_size() {
+    let start = Instant::now();
+    let _ = &Vector::from(vec![start]) + &Vector::from(vec![Duration::ZERO, Duration::ZERO]);
+}
@@ -0,0 +1,43 @@
+use std::ops::*;
+
+use simp_linalg_proc_macro::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vector<T> {
+    list: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(list: Vec<T>) -> Self {
+        Vector { list }
+    }
+}
+
+// With the `parallel` feature, vectors of at least 4 elements run with `rayon`.
+vector_ops_impl!(Vector<T>: add, sub, dot, scalar_mul, parallel_threshold = 4);
+
+#[derive(VectorOps, Debug, Clone, PartialEq)]
+#[vector_ops(ops(add, scalar_mul), parallel_threshold = 4)]
+pub struct Series(Vec<i64>);
+
+#[test]
+fn results_are_the_same_on_both_sides_of_the_threshold() {
+    for length in [0, 3, 4, 1000] {
+        let a: Vec<i64> = (0..length).collect();
+        let b: Vec<i64> = (0..length).map(|x| 2 * x + 1).collect();
+
+        let sum: Vec<i64> = a.iter().zip(&b).map(|(a, b)| a + b).collect();
+        let difference: Vec<i64> = a.iter().zip(&b).map(|(a, b)| a - b).collect();
+        let dot: i64 = a.iter().zip(&b).map(|(a, b)| a * b).sum();
+        let scaled: Vec<i64> = a.iter().map(|a| a * 3).collect();
+
+        let (va, vb) = (Vector::from(a.clone()), Vector::from(b.clone()));
+        assert_eq!((&va + &vb).list, sum);
+        assert_eq!((va.clone() - &vb).list, difference);
+        assert_eq!(&va * &vb, dot);
+        assert_eq!((&va * 3).list, scaled);
+
+        assert_eq!((&Series(a.clone()) + &Series(b.clone())).0, sum);
+        assert_eq!((Series(a) * 3).0, scaled);
+    }
+}
@@ -0,0 +1,85 @@
+use std::ops::*;
+
+use simp_linalg_proc_macro::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vector<T> {
+    list: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(list: Vec<T>) -> Self {
+        Vector { list }
+    }
+}
+
+/// The affine map `x -> scale * x + shift`, multiplied by composition,
+/// which does not commute.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Affine {
+    scale: i32,
+    shift: i32,
+}
+
+impl Mul for Affine {
+    type Output = Affine;
+
+    fn mul(self, rhs: Affine) -> Affine {
+        Affine {
+            scale: self.scale * rhs.scale,
+            shift: self.scale * rhs.shift + self.shift,
+        }
+    }
+}
+
+vector_scalar_mul_impl!(impl<> &Vector<Affine> Affine);
+vector_scalar_mul_impl!(impl<> &mut Vector<Affine> Affine);
+vector_scalar_mul_impl!(impl<> Vector<Affine> Affine);
+
+vector_scalar_div_impl!(&Vector<T> T);
+vector_scalar_div_impl!(&mut Vector<T> T);
+vector_scalar_div_impl!(Vector<T> T);
+vector_scalar_rem_impl!(&Vector<T> T);
+vector_scalar_rem_impl!(&mut Vector<T> T);
+vector_scalar_rem_impl!(Vector<T> T);
+
+#[test]
+fn the_scalar_is_the_left_factor_of_each_product() {
+    let maps = vec![Affine { scale: 2, shift: 1 }, Affine { scale: 1, shift: -3 }];
+    let scalar = Affine { scale: 3, shift: 5 };
+    let expected: Vec<Affine> = maps.iter().map(|map| scalar * *map).collect();
+    assert_ne!(expected, maps.iter().map(|map| *map * scalar).collect::<Vec<_>>());
+
+    let vector = Vector::from(maps);
+    assert_eq!((&vector * scalar).list, expected);
+    assert_eq!((vector.clone() * scalar).list, expected);
+
+    let mut borrowed = vector;
+    let _ = &mut borrowed * scalar;
+    assert_eq!(borrowed.list, expected);
+}
+
+#[test]
+fn integer_division_and_remainder() {
+    let v = Vector::from(vec![7, -9, 12]);
+
+    assert_eq!(&v / 4, Vector::from(vec![1, -2, 3]));
+    assert_eq!(v.clone() / 4, Vector::from(vec![1, -2, 3]));
+    assert_eq!(&v % 4, Vector::from(vec![3, -1, 0]));
+    assert_eq!(v.clone() % 4, Vector::from(vec![3, -1, 0]));
+
+    let mut quotient = v.clone();
+    let _ = &mut quotient / 2;
+    assert_eq!(quotient, Vector::from(vec![3, -4, 6]));
+
+    let mut remainder = v;
+    let result = &mut remainder % 5;
+    result.list[0] += 10;
+    assert_eq!(remainder, Vector::from(vec![12, -4, 2]));
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn division_by_zero_panics_like_the_elements() {
+    let _ = &Vector::from(vec![1, 2]) / 0;
+}
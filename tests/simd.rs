@@ -0,0 +1,109 @@
+use std::ops::*;
+
+use simp_linalg_proc_macro::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Samples<T> {
+    list: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for Samples<T> {
+    fn from(list: Vec<T>) -> Self {
+        Samples { list }
+    }
+}
+
+// With the `simd` feature, these take the explicit AVX path.
+vector_add_impl!(&Samples<f32> &Samples<f32>);
+vector_sub_impl!(Samples<f32> &Samples<f32>);
+vector_add_impl!(&mut Samples<f64> &Samples<f64>);
+vector_dot_prod_impl!(&Samples<f32> &Samples<f32>);
+vector_dot_prod_impl!(&Samples<f64> &Samples<f64>);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vector<T> {
+    list: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(list: Vec<T>) -> Self {
+        Vector { list }
+    }
+}
+
+// The generic impls are the same with or without the feature,
+// so they also hold for element types which are not `'static`.
+vector_add_impl!(&Vector<T> &Vector<T>);
+vector_dot_prod_impl!(&Vector<T> &Vector<T>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Labeled<'a> {
+    value: f32,
+    label: &'a str,
+}
+
+impl<'a> Add for Labeled<'a> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Labeled { value: self.value + rhs.value, label: self.label }
+    }
+}
+
+impl AddAssign for Labeled<'_> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.value += rhs.value
+    }
+}
+
+impl<'a> Mul for Labeled<'a> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Labeled { value: self.value * rhs.value, label: self.label }
+    }
+}
+
+/// Values which are exactly representable, so every summation order agrees.
+fn values(length: usize, scale: f32) -> Vec<f32> {
+    (0..length).map(|idx| (idx % 13) as f32 * scale).collect()
+}
+
+#[test]
+fn float_operators_match_a_scalar_loop() {
+    // Lengths around the register widths and the unrolled dot product step.
+    for length in 0..70 {
+        let a = values(length, 0.5);
+        let b = values(length, 0.25);
+
+        let sum: Vec<f32> = a.iter().zip(&b).map(|(a, b)| a + b).collect();
+        let difference: Vec<f32> = a.iter().zip(&b).map(|(a, b)| a - b).collect();
+        let dot: f32 = a.iter().zip(&b).map(|(a, b)| a * b).sum();
+
+        let (a, b) = (Samples::from(a), Samples::from(b));
+
+        assert_eq!((&a + &b).list, sum);
+        assert_eq!((a.clone() - &b).list, difference);
+        assert_eq!(&a * &b, dot);
+
+        let a: Samples<f64> = Samples::from(a.list.iter().map(|&x| x as f64).collect::<Vec<_>>());
+        let b: Samples<f64> = Samples::from(b.list.iter().map(|&x| x as f64).collect::<Vec<_>>());
+
+        let mut c = a.clone();
+        let _ = &mut c + &b;
+        assert_eq!(c.list, sum.iter().map(|&x| x as f64).collect::<Vec<_>>());
+        assert_eq!(&a * &b, dot as f64);
+    }
+}
+
+#[test]
+fn generic_impls_accept_borrowing_elements() {
+    let label = String::from("meters");
+
+    let a = Vector::from(vec![Labeled { value: 1.0, label: &label }, Labeled { value: 2.0, label: &label }]);
+    let b = Vector::from(vec![Labeled { value: 3.0, label: &label }, Labeled { value: 4.0, label: &label }]);
+
+    let sum = &a + &b;
+    assert_eq!(sum.list.iter().map(|item| item.value).collect::<Vec<_>>(), vec![4.0, 6.0]);
+    assert_eq!((&a * &b).value, 11.0);
+}
@@ -0,0 +1,77 @@
+use std::ops::*;
+
+use simp_linalg_proc_macro::*;
+
+macro_rules! vector_type {
+    ($name:ident) => {
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $name<T> {
+            list: Vec<T>,
+        }
+
+        impl<T> From<Vec<T>> for $name<T> {
+            fn from(list: Vec<T>) -> Self {
+                $name { list }
+            }
+        }
+    };
+}
+
+vector_type!(Naive);
+vector_type!(Kahan);
+vector_type!(Pairwise);
+
+vector_dot_prod_impl!(&Naive<T> &Naive<T>);
+vector_dot_prod_impl!(&Kahan<T> &Kahan<T>, summation = kahan);
+vector_dot_prod_impl!(&Pairwise<T> &Pairwise<T>, summation = pairwise);
+
+/// The dot products of `a` and `b` with each summation.
+fn dot_products(a: &[f32], b: &[f32]) -> [f32; 3] {
+    [
+        &Naive::from(a.to_vec()) * &Naive::from(b.to_vec()),
+        &Kahan::from(a.to_vec()) * &Kahan::from(b.to_vec()),
+        &Pairwise::from(a.to_vec()) * &Pairwise::from(b.to_vec()),
+    ]
+}
+
+#[test]
+fn many_small_terms_after_a_large_one() {
+    // 1e8 followed by a million ones, each of which is lost when added
+    // to 1e8 on its own in `f32`, while the exact sum is representable.
+    let mut a = vec![1.0f32; 1_000_001];
+    a[0] = 1e8;
+    let b = vec![1.0f32; a.len()];
+
+    let exact = 101_000_000.0f32;
+    let [naive, kahan, pairwise] = dot_products(&a, &b);
+
+    assert_eq!(kahan, exact);
+    // Within two units in the last place, which are 8 at this magnitude.
+    assert!((pairwise - exact).abs() <= 16.0, "pairwise: {}", pairwise);
+    // The naive summation loses the ones added to the partial sum holding 1e8.
+    assert!((naive - exact).abs() / exact <= 2e-3, "naive: {}", naive);
+}
+
+#[test]
+fn long_random_dot_products() {
+    // A fixed linear congruential generator, so the test is deterministic.
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let mut next = move || {
+        state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+        (state >> 40) as f32 / (1u64 << 24) as f32
+    };
+
+    let a: Vec<f32> = (0..1_000_000).map(|_| next()).collect();
+    let b: Vec<f32> = (0..1_000_000).map(|_| next()).collect();
+
+    let reference: f64 = a.iter().zip(&b).map(|(a, b)| *a as f64 * *b as f64).sum();
+    let relative_error = |product: f32| (product as f64 - reference).abs() / reference;
+
+    let [naive, kahan, pairwise] = dot_products(&a, &b);
+
+    // Kahan's summation is within about one rounding of the `f32` result,
+    // the pairwise one within a few, and the naive one drifts with the length.
+    assert!(relative_error(kahan) <= 2.0 * f32::EPSILON as f64, "kahan: {}", kahan);
+    assert!(relative_error(pairwise) <= 1e-6, "pairwise: {}", pairwise);
+    assert!(relative_error(naive) <= 1e-5, "naive: {}", naive);
+}
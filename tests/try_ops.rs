@@ -0,0 +1,48 @@
+use std::ops::*;
+
+use simp_linalg_proc_macro::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vector<T> {
+    list: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(list: Vec<T>) -> Self {
+        Vector { list }
+    }
+}
+
+vector_traits!();
+
+vector_try_add_impl!(&Vector<T> &Vector<T>);
+vector_try_sub_impl!(&Vector<T> &Vector<T>);
+vector_try_dot_prod_impl!(&Vector<T> &Vector<T>);
+
+#[test]
+fn mismatch_messages_name_the_operation() {
+    let a = Vector::from(vec![1, 2, 3]);
+    let b = Vector::from(vec![4, 5]);
+
+    let add = (&a).try_add(&b).unwrap_err();
+    assert_eq!(add, DimensionMismatch { op: TryOp::Add, lhs_len: 3, rhs_len: 2 });
+    assert_eq!(add.to_string(), "Cannot add vectors of lengths 3 and 2.");
+
+    let sub = (&a).try_sub(&b).unwrap_err();
+    assert_eq!(sub.op, TryOp::Sub);
+    assert_eq!(sub.to_string(), "Cannot subtract vectors of lengths 3 and 2.");
+
+    let dot = (&a).try_dot(&b).unwrap_err();
+    assert_eq!(dot.op, TryOp::Dot);
+    assert_eq!(dot.to_string(), "Cannot take the dot product of vectors of lengths 3 and 2.");
+}
+
+#[test]
+fn same_size_succeeds() {
+    let a = Vector::from(vec![1, 2, 3]);
+    let b = Vector::from(vec![4, 5, 6]);
+
+    assert_eq!((&a).try_add(&b), Ok(Vector::from(vec![5, 7, 9])));
+    assert_eq!((&b).try_sub(&a), Ok(Vector::from(vec![3, 3, 3])));
+    assert_eq!((&a).try_dot(&b), Ok(32));
+}
@@ -0,0 +1,125 @@
+//! Checks that the loops generated for `f32` and `f64` vectors are vectorized,
+//! by building a crate using the macros in release mode and reading its assembly.
+//! The matching timings are in `benches/elementwise.rs`.
+#![cfg(target_arch = "x86_64")]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The crate whose assembly is checked, where each function
+/// runs one generated operator on a generic vector.
+const FIXTURE: &str = r#"
+use std::ops::*;
+use simp_linalg_proc_macro::*;
+
+pub struct Vector<T> {
+    list: Vec<T>,
+}
+
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(list: Vec<T>) -> Self {
+        Vector { list }
+    }
+}
+
+vector_ops_impl!(Vector<T>: add, sub, dot, scalar_mul);
+
+#[no_mangle]
+pub fn add_f32(a: &Vector<f32>, b: &Vector<f32>) -> Vector<f32> { a + b }
+#[no_mangle]
+pub fn add_f64(a: &Vector<f64>, b: &Vector<f64>) -> Vector<f64> { a + b }
+#[no_mangle]
+pub fn sub_in_place_f32(a: &mut Vector<f32>, b: &Vector<f32>) { let _ = a - b; }
+#[no_mangle]
+pub fn sub_in_place_f64(a: &mut Vector<f64>, b: &Vector<f64>) { let _ = a - b; }
+#[no_mangle]
+pub fn scale_f32(a: Vector<f32>) -> Vector<f32> { a * 3.0 }
+#[no_mangle]
+pub fn scale_f64(a: Vector<f64>) -> Vector<f64> { a * 3.0 }
+#[no_mangle]
+pub fn dot_f32(a: &Vector<f32>, b: &Vector<f32>) -> f32 { a * b }
+#[no_mangle]
+pub fn dot_f64(a: &Vector<f64>, b: &Vector<f64>) -> f64 { a * b }
+"#;
+
+/// Builds the fixture for the baseline `x86-64` CPU, which has SSE2,
+/// and returns its assembly.
+fn fixture_assembly() -> String {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = manifest_dir.join("target").join("vectorization");
+    let crate_dir = target_dir.join("fixture");
+
+    fs::create_dir_all(crate_dir.join("src")).unwrap();
+    fs::write(
+        crate_dir.join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"vectorization_fixture\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+             [dependencies]\nsimp_linalg_proc_macro = {{ path = {:?} }}\n\n[workspace]\n",
+            manifest_dir
+        ),
+    ).unwrap();
+    fs::write(crate_dir.join("src").join("lib.rs"), FIXTURE).unwrap();
+
+    let status = Command::new(option_env!("CARGO").unwrap_or("cargo"))
+        .args(["rustc", "--release", "--lib", "--offline", "--quiet", "--manifest-path"])
+        .arg(crate_dir.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .args(["--", "--emit", "asm", "-C", "target-cpu=x86-64"])
+        .status()
+        .unwrap();
+    assert!(status.success(), "the fixture failed to build");
+
+    let assembly: PathBuf = fs::read_dir(target_dir.join("release").join("deps"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            let name = path.file_name().unwrap().to_string_lossy();
+            name.starts_with("vectorization_fixture") && name.ends_with(".s")
+        })
+        .max_by_key(|path| path.metadata().unwrap().modified().unwrap())
+        .expect("no assembly was emitted");
+
+    fs::read_to_string(assembly).unwrap()
+}
+
+/// The instructions of the function named `name`.
+fn function<'a>(assembly: &'a str, name: &str) -> &'a str {
+    let start = assembly.find(&format!("\n{}:", name))
+        .unwrap_or_else(|| panic!("`{}` is missing from the assembly", name));
+    let body = &assembly[start..];
+    let end = body.find(".cfi_endproc").unwrap_or(body.len());
+
+    &body[..end]
+}
+
+#[test]
+#[ignore = "builds a separate crate in release mode, run with `cargo test -- --ignored`"]
+fn float_loops_are_vectorized() {
+    let assembly = fixture_assembly();
+
+    // Packed instructions, as opposed to the scalar `addss` or `addsd`.
+    let expected = [
+        ("add_f32", ["addps"].as_slice()),
+        ("add_f64", &["addpd"]),
+        ("sub_in_place_f32", &["subps"]),
+        ("sub_in_place_f64", &["subpd"]),
+        ("scale_f32", &["mulps"]),
+        ("scale_f64", &["mulpd"]),
+        ("dot_f32", &["mulps", "addps"]),
+        ("dot_f64", &["mulpd", "addpd"]),
+    ];
+
+    for (name, instructions) in expected {
+        let body = function(&assembly, name);
+
+        for instruction in instructions {
+            assert!(
+                body.contains(instruction),
+                "`{}` does not use `{}`:\n{}",
+                name, instruction, body
+            );
+        }
+    }
+}